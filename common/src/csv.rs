@@ -0,0 +1,76 @@
+use crate::ParseError;
+
+/// Quotes `value` when it holds a separator, a quote or a line break, as RFC 4180 does.
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Splits one CSV record into its fields, unquoting them as RFC 4180 does.
+///
+/// Quoted fields may hold separators and doubled quotes, but not line breaks.
+pub fn csv_fields(line: &str) -> Result<Vec<String>, ParseError> {
+    let mut fields: Vec<String> = Vec::new();
+    let mut rest = line;
+
+    loop {
+        let mut field = String::new();
+
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let mut chars = quoted.char_indices();
+
+            rest = loop {
+                match chars.next() {
+                    Some((index, '"')) if quoted[index + 1..].starts_with('"') => {
+                        field.push('"');
+                        chars.next();
+                    }
+                    Some((index, '"')) => break &quoted[index + 1..],
+                    Some((_, c)) => field.push(c),
+                    None => return Err(ParseError::at(line, rest, "Unterminated quoted field")),
+                }
+            };
+
+            if !rest.is_empty() && !rest.starts_with(',') {
+                return Err(ParseError::at(
+                    line,
+                    rest,
+                    "Expected `,` after quoted field",
+                ));
+            }
+        } else {
+            let end = rest.find(',').unwrap_or(rest.len());
+            field.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+
+        fields.push(field);
+
+        match rest.strip_prefix(',') {
+            Some(x) => rest = x,
+            None => return Ok(fields),
+        }
+    }
+}
+
+#[test]
+fn test_csv_fields() {
+    let fields = vec!["1", "Elf \"Bob\", Jr.", "", "x"];
+    let line = fields
+        .iter()
+        .map(|e| csv_field(e))
+        .collect::<Vec<String>>()
+        .join(",");
+
+    assert_eq!(line, "1,\"Elf \"\"Bob\"\", Jr.\",,x");
+    assert_eq!(csv_fields(&line).unwrap(), fields);
+
+    let error = csv_fields("1,\"open,2").unwrap_err();
+    assert_eq!(
+        (error.column, error.message.as_str()),
+        (3, "Unterminated quoted field")
+    );
+}
@@ -0,0 +1,217 @@
+use std::{error::Error, fmt, io, str::FromStr};
+
+/// Malformed puzzle input, pointing at the offending text.
+///
+/// `line` and `column` are 1-based; `0` means the position is not known yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error about `token`, a slice of `line`, with the column taken from its position in `line`.
+    pub fn at(line: &str, token: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: 0,
+            column: column_of(line, token),
+            text: token.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Error about a token missing from the end of `line`.
+    pub fn missing(line: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: 0,
+            column: line.chars().count() + 1,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_owned());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+            self.message
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, " (found end of line)")
+        } else {
+            write!(f, " (found `{}`)", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn column_of(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+
+    match line.get(..offset) {
+        Some(prefix) if offset <= line.len() => prefix.chars().count() + 1,
+        _ => 1,
+    }
+}
+
+/// Parses `token`, a slice of `line`, reporting failures at its position.
+pub fn parse_token<T>(line: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    token
+        .parse::<T>()
+        .map_err(|e| ParseError::at(line, token, e.to_string()))
+}
+
+/// Returns `tokens[index]`, or an error naming the missing `what` at the end of `line`.
+pub fn token<'a>(
+    line: &str,
+    tokens: &[&'a str],
+    index: usize,
+    what: &str,
+) -> Result<&'a str, ParseError> {
+    tokens
+        .get(index)
+        .copied()
+        .ok_or_else(|| ParseError::missing(line, format!("Missing {}", what)))
+}
+
+/// Parses every line of `input` with `parse_line`, numbering errors by line.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|e| e.on_line(index + 1)))
+        .collect()
+}
+
+/// Parses `input` as groups of records separated by blank lines, with `parse_record` called
+/// on every record line.
+///
+/// Line endings may be `\n` or `\r\n`, whitespace-only lines count as blank, runs of blank
+/// lines count as a single separator, lines starting with `#` are skipped, and the last
+/// group needs no trailing blank line.
+pub fn parse_groups<T>(
+    input: &str,
+    mut parse_record: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut groups: Vec<Vec<T>> = Vec::new();
+    let mut group: Vec<T> = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(group);
+                group = Vec::new();
+            }
+        } else if !line.trim_start().starts_with('#') {
+            group.push(parse_record(line).map_err(|e| e.on_line(index + 1))?);
+        }
+    }
+
+    if !group.is_empty() {
+        groups.push(group);
+    }
+
+    Ok(groups)
+}
+
+/// Failure to load a puzzle input from disk or stdin.
+#[derive(Debug)]
+pub enum InputError {
+    Io(String, io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(path, e) => write!(f, "Cannot read {}: {}", path, e),
+            InputError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(_, e) => Some(e),
+            InputError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(value: ParseError) -> Self {
+        InputError::Parse(value)
+    }
+}
+
+#[test]
+fn test_column_of_token() {
+    let line = "move 1 from x to 3";
+    let tokens = line.split(' ').collect::<Vec<&str>>();
+
+    let error = parse_token::<usize>(line, tokens[3])
+        .unwrap_err()
+        .on_line(7);
+
+    assert_eq!((error.line, error.column), (7, 13));
+    assert_eq!(error.text, "x");
+    assert_eq!(
+        error.in_file("day5/input.txt").to_string(),
+        "day5/input.txt:7:13: invalid digit found in string (found `x`)"
+    );
+}
+
+#[test]
+fn test_missing_token() {
+    let line = "addx";
+    let tokens = line.split(' ').collect::<Vec<&str>>();
+
+    let error = token(line, &tokens, 1, "operand").unwrap_err();
+
+    assert_eq!(error.column, 5);
+    assert_eq!(error.message, "Missing operand");
+}
+
+#[test]
+fn test_groups() {
+    let input = "# inventory\r\n1\r\n 2 \r\n\r\n\r\n  \n3\n# 4\n5";
+
+    let groups = parse_groups(input, |line| parse_token::<i32>(line, line.trim())).unwrap();
+
+    assert_eq!(groups, vec![vec![1, 2], vec![3, 5]]);
+
+    let error = parse_groups("1\n\n2\n3x\n", |line| parse_token::<i32>(line, line)).unwrap_err();
+
+    assert_eq!((error.line, error.column), (4, 1));
+}
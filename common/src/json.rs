@@ -0,0 +1,422 @@
+use std::fmt::{self, Write};
+
+use crate::ParseError;
+
+/// Minimal JSON document model, enough for baselines, reports and data exchange.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn parse(text: &str) -> Result<Json, ParseError> {
+        let mut parser = Parser {
+            text,
+            chars: text.char_indices().collect(),
+            position: 0,
+        };
+
+        parser.skip_whitespace();
+        let value = parser.value()?;
+        parser.skip_whitespace();
+
+        if parser.position != parser.chars.len() {
+            return Err(parser.error("Unexpected trailing characters"));
+        }
+
+        Ok(value)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Json::Integer(x) => Some(*x),
+            Json::Number(x) if x.fract() == 0.0 => Some(*x as i64),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Integer(x) => Some(*x as f64),
+            Json::Number(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Serializes with one array element or object field per line.
+    pub fn pretty(&self) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize) {
+        let pad = "  ".repeat(indent + 1);
+
+        match self {
+            Json::Array(values) if !values.is_empty() => {
+                out.push_str("[\n");
+                for (index, value) in values.iter().enumerate() {
+                    out.push_str(&pad);
+                    value.write_pretty(out, indent + 1);
+                    out.push_str(if index + 1 < values.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                out.push_str(&"  ".repeat(indent));
+                out.push(']');
+            }
+            Json::Object(fields) if !fields.is_empty() => {
+                out.push_str("{\n");
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    out.push_str(&pad);
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write_pretty(out, indent + 1);
+                    out.push_str(if index + 1 < fields.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                out.push_str(&"  ".repeat(indent));
+                out.push('}');
+            }
+            x => out.push_str(&x.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(x) => write!(f, "{}", x),
+            Json::Integer(x) => write!(f, "{}", x),
+            Json::Number(x) if x.is_finite() => write!(f, "{}", x),
+            Json::Number(_) => write!(f, "null"),
+            Json::String(x) => {
+                let mut out = String::new();
+                write_string(&mut out, x);
+                f.write_str(&out)
+            }
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}:{}", Json::String(key.clone()), value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_owned())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl From<i64> for Json {
+    fn from(value: i64) -> Self {
+        Json::Integer(value)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(value: f64) -> Self {
+        Json::Number(value)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    chars: Vec<(usize, char)>,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).map(|(_, c)| *c)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.position += 1;
+        c
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        let offset = match self.chars.get(self.position) {
+            Some((offset, _)) => *offset,
+            None => self.text.len(),
+        };
+
+        let line_start = self.text[..offset].rfind('\n').map_or(0, |e| e + 1);
+        let line_end = self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |e| offset + e);
+        let line = &self.text[line_start..line_end];
+        let number = self.text[..offset].matches('\n').count() + 1;
+
+        let token = match self.chars.get(self.position) {
+            Some((_, c)) if *c != '\n' => {
+                &line[offset - line_start..offset - line_start + c.len_utf8()]
+            }
+            _ => return ParseError::missing(line, message).on_line(number),
+        };
+
+        ParseError::at(line, token, message).on_line(number)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected `{}`", expected)))
+        }
+    }
+
+    fn keyword(&mut self, word: &str, value: Json) -> Result<Json, ParseError> {
+        for expected in word.chars() {
+            self.expect(expected)?;
+        }
+
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Json, ParseError> {
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => self.string().map(Json::String),
+            Some('t') => self.keyword("true", Json::Bool(true)),
+            Some('f') => self.keyword("false", Json::Bool(false)),
+            Some('n') => self.keyword("null", Json::Null),
+            Some('-' | '0'..='9') => self.number(),
+            _ => Err(self.error("Expected a value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, ParseError> {
+        let mut fields: Vec<(String, Json)> = Vec::new();
+
+        self.expect('{')?;
+        self.skip_whitespace();
+
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Json::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            self.skip_whitespace();
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(Json::Object(fields)),
+                _ => {
+                    self.position -= 1;
+                    return Err(self.error("Expected `,` or `}`"));
+                }
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, ParseError> {
+        let mut values: Vec<Json> = Vec::new();
+
+        self.expect('[')?;
+        self.skip_whitespace();
+
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Json::Array(values));
+        }
+
+        loop {
+            self.skip_whitespace();
+            values.push(self.value()?);
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(Json::Array(values)),
+                _ => {
+                    self.position -= 1;
+                    return Err(self.error("Expected `,` or `]`"));
+                }
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        let mut out = String::new();
+
+        self.expect('"')?;
+
+        loop {
+            match self.next() {
+                Some('"') => return Ok(out),
+                Some('\\') => match self.next() {
+                    Some('"') => out.push('"'),
+                    Some('\\') => out.push('\\'),
+                    Some('/') => out.push('/'),
+                    Some('n') => out.push('\n'),
+                    Some('r') => out.push('\r'),
+                    Some('t') => out.push('\t'),
+                    Some('b') => out.push('\u{8}'),
+                    Some('f') => out.push('\u{c}'),
+                    Some('u') => {
+                        let mut code = 0;
+                        for _ in 0..4 {
+                            let digit = self.peek().and_then(|e| e.to_digit(16));
+                            match digit {
+                                Some(x) => code = code * 16 + x,
+                                None => return Err(self.error("Expected a hex digit")),
+                            }
+                            self.position += 1;
+                        }
+                        out.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                    }
+                    _ => {
+                        self.position -= 1;
+                        return Err(self.error("Unknown escape sequence"));
+                    }
+                },
+                Some(c) => out.push(c),
+                None => return Err(self.error("Unterminated string")),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, ParseError> {
+        let start = self.position;
+
+        while matches!(self.peek(), Some('-' | '+' | '.' | 'e' | 'E' | '0'..='9')) {
+            self.position += 1;
+        }
+
+        let text = self.chars[start..self.position]
+            .iter()
+            .map(|(_, c)| c)
+            .collect::<String>();
+
+        if let Ok(x) = text.parse::<i64>() {
+            return Ok(Json::Integer(x));
+        }
+
+        match text.parse::<f64>() {
+            Ok(x) => Ok(Json::Number(x)),
+            Err(_) => {
+                self.position = start;
+                Err(self.error("Invalid number"))
+            }
+        }
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let text = r#"{"day": 1, "name": "a \"b\"\n", "values": [1.5, -2, true, null], "empty": {}}"#;
+
+    let value = Json::parse(text).unwrap();
+
+    assert_eq!(value.get("day").and_then(Json::as_i64), Some(1));
+    assert_eq!(value.get("name").and_then(Json::as_str), Some("a \"b\"\n"));
+    assert_eq!(Json::parse(&value.to_string()).unwrap(), value);
+    assert_eq!(Json::parse(&value.pretty()).unwrap(), value);
+}
+
+#[test]
+fn test_error_position() {
+    let error = Json::parse("{\n  \"day\": 1,\n  \"part\" 2\n}").unwrap_err();
+
+    assert_eq!((error.line, error.column), (3, 10));
+    assert_eq!(error.message, "Expected `:`");
+}
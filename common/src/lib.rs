@@ -0,0 +1,188 @@
+use std::{
+    env, fmt,
+    fs::File,
+    io::{self, BufReader, Read},
+    time::{Duration, Instant},
+};
+
+mod csv;
+mod error;
+mod json;
+mod provider;
+mod rng;
+
+pub use csv::{csv_field, csv_fields};
+pub use error::{parse_groups, parse_lines, parse_token, token, InputError, ParseError};
+pub use json::Json;
+pub use provider::{
+    default_provider, Cached, DirectoryCache, HttpFetcher, InputCache, InputProvider, UserFiles,
+    BASE_URL_VAR, DEFAULT_BASE_URL, SESSION_VAR, USER_VAR, YEAR,
+};
+pub use rng::Rng;
+
+/// Environment variable naming a directory laid out like the workspace (`dayN/input.txt`).
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Answer produced by a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(x) => write!(f, "{}", x),
+            Answer::Text(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// A day of the calendar: parses its puzzle input once and answers both parts from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Random but valid puzzle input with roughly `size` records (lines, groups, ...).
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Answer to one part, with the time spent parsing the input and solving the part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Parses `input` and answers the requested part, or both parts when `part` is `None`.
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Vec<Record>, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let record = |part: u8, function: fn(&S::Input) -> Answer| {
+        let start = Instant::now();
+        let answer = function(&parsed);
+
+        Record {
+            day: S::DAY,
+            part,
+            answer,
+            parse_time,
+            solve_time: start.elapsed(),
+        }
+    };
+
+    let mut records: Vec<Record> = Vec::new();
+
+    if part.is_none() || part == Some(1) {
+        records.push(record(1, S::part1));
+    }
+
+    if part.is_none() || part == Some(2) {
+        records.push(record(2, S::part2));
+    }
+
+    Ok(records)
+}
+
+/// Directory holding the `dayN/` input folders: `AOC_INPUT_DIR`, or the current directory
+/// when it is not set.
+pub fn input_dir() -> String {
+    env::var(INPUT_DIR_VAR).unwrap_or_else(|_| ".".to_owned())
+}
+
+/// Path of the input called `name` for `day` under `dir`, e.g. `dir/day1/example.txt`.
+pub fn input_path(dir: &str, day: u8, name: &str) -> String {
+    format!("{}/day{}/{}.txt", dir.trim_end_matches('/'), day, name)
+}
+
+/// Path of the input used when none is given: `dayN/input.txt` under `input_dir()`.
+pub fn default_input_path(day: u8) -> String {
+    input_path(&input_dir(), day, "input")
+}
+
+/// Reads a whole puzzle input from `path`, or from stdin when `path` is `-`.
+pub fn read_input(path: &str) -> io::Result<String> {
+    let mut buf = String::new();
+
+    if path == "-" {
+        io::stdin().lock().read_to_string(&mut buf)?;
+    } else {
+        let file = File::open(path)?;
+        let mut reader = BufReader::new(file);
+
+        reader.read_to_string(&mut buf)?;
+    }
+
+    Ok(buf)
+}
+
+/// Reads and parses the input at `path` (or stdin for `-`), naming the file in parse errors.
+pub fn load<S: Solution>(path: &str) -> Result<S::Input, InputError> {
+    let input = read_input(path).map_err(|e| InputError::Io(path.to_owned(), e))?;
+
+    S::parse(&input).map_err(|e| InputError::Parse(e.in_file(input_name(path))))
+}
+
+/// Name of the input at `path` as shown in diagnostics.
+pub fn input_name(path: &str) -> &str {
+    if path == "-" {
+        "<stdin>"
+    } else {
+        path
+    }
+}
@@ -0,0 +1,346 @@
+use std::{
+    env, fs,
+    io::{self, Read, Write},
+    net::TcpStream,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use crate::{input_dir, input_path, read_input};
+
+/// Environment variable holding the adventofcode.com `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable selecting the file-per-user layout for the given user name.
+pub const USER_VAR: &str = "AOC_USER";
+
+/// Environment variable overriding the server inputs are downloaded from.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub const YEAR: u16 = 2022;
+
+/// Somewhere a day's puzzle input can be obtained from.
+pub trait InputProvider {
+    /// Where the input for `day` lives, as shown in diagnostics.
+    fn location(&self, day: u8) -> String;
+
+    fn fetch(&self, day: u8) -> io::Result<String>;
+}
+
+/// Provider that can also keep inputs obtained elsewhere.
+pub trait InputCache: InputProvider {
+    fn store(&self, day: u8, input: &str) -> io::Result<()>;
+}
+
+fn read_file(path: &str) -> io::Result<String> {
+    read_input(path).map_err(|e| io::Error::new(e.kind(), format!("Cannot read {}: {}", path, e)))
+}
+
+fn write_file(path: &str, input: &str) -> io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, input)
+        .map_err(|e| io::Error::new(e.kind(), format!("Cannot write {}: {}", path, e)))
+}
+
+/// Inputs stored as `dayN/input.txt` under a directory.
+pub struct DirectoryCache {
+    pub dir: String,
+}
+
+impl InputProvider for DirectoryCache {
+    fn location(&self, day: u8) -> String {
+        input_path(&self.dir, day, "input")
+    }
+
+    fn fetch(&self, day: u8) -> io::Result<String> {
+        read_file(&self.location(day))
+    }
+}
+
+impl InputCache for DirectoryCache {
+    fn store(&self, day: u8, input: &str) -> io::Result<()> {
+        write_file(&self.location(day), input)
+    }
+}
+
+/// Inputs of several accounts side by side, stored as `dayN/<user>.txt` under a directory.
+pub struct UserFiles {
+    pub dir: String,
+    pub user: String,
+}
+
+impl InputProvider for UserFiles {
+    fn location(&self, day: u8) -> String {
+        input_path(&self.dir, day, &self.user)
+    }
+
+    fn fetch(&self, day: u8) -> io::Result<String> {
+        read_file(&self.location(day))
+    }
+}
+
+impl InputCache for UserFiles {
+    fn store(&self, day: u8, input: &str) -> io::Result<()> {
+        write_file(&self.location(day), input)
+    }
+}
+
+/// Downloads inputs from the puzzle server, authenticated with a session token.
+///
+/// Plain `http://` servers are spoken to directly; `https://` goes through `curl`.
+pub struct HttpFetcher {
+    pub base_url: String,
+    pub session: String,
+}
+
+const USER_AGENT: &str = "github.com/Golzeb/aoc2022 input fetcher";
+
+impl HttpFetcher {
+    fn url(&self, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            YEAR,
+            day
+        )
+    }
+
+    fn get_http(&self, url: &str, rest: &str) -> io::Result<String> {
+        let (host, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, "/"),
+        };
+        let address = if host.contains(':') {
+            host.to_owned()
+        } else {
+            format!("{}:80", host)
+        };
+
+        let download = || -> io::Result<String> {
+            let mut stream = TcpStream::connect(&address)?;
+
+            // HTTP/1.0 keeps the response unchunked and ends it by closing the connection.
+            write!(
+                stream,
+                "GET {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\n\r\n",
+                path, host, USER_AGENT, self.session
+            )?;
+
+            let mut response = String::new();
+            stream.read_to_string(&mut response)?;
+
+            Ok(response)
+        };
+
+        let response = download()
+            .map_err(|e| io::Error::new(e.kind(), format!("Cannot download {}: {}", url, e)))?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| invalid(format!("Malformed response from {}", url)))?;
+
+        let status = head
+            .lines()
+            .next()
+            .and_then(|e| e.split(' ').nth(1))
+            .and_then(|e| e.parse::<u16>().ok())
+            .ok_or_else(|| invalid(format!("Malformed status line from {}", url)))?;
+
+        if status != 200 {
+            return Err(io::Error::other(format!(
+                "{} answered with HTTP {}{}",
+                url,
+                status,
+                match status {
+                    400 | 401 | 403 => " (is the session token valid?)",
+                    404 => " (is the puzzle unlocked yet?)",
+                    _ => "",
+                }
+            )));
+        }
+
+        Ok(body.to_owned())
+    }
+
+    fn get_https(&self, url: &str) -> io::Result<String> {
+        // The token goes through stdin so it never shows up in the process list.
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--config", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| io::Error::new(e.kind(), format!("Cannot run curl: {}", e)))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            write!(
+                stdin,
+                "url = {}\nuser-agent = {}\nheader = {}\n",
+                curl_quote(url),
+                curl_quote(USER_AGENT),
+                curl_quote(&format!("Cookie: session={}", self.session))
+            )?;
+        }
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "Cannot download {}: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        String::from_utf8(output.stdout).map_err(|_| invalid(format!("{} is not valid UTF-8", url)))
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Quotes `value` for a curl config file.
+fn curl_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl InputProvider for HttpFetcher {
+    fn location(&self, day: u8) -> String {
+        self.url(day)
+    }
+
+    fn fetch(&self, day: u8) -> io::Result<String> {
+        // The token ends up in a header and a curl config, where anything but letters and
+        // digits could add lines of its own.
+        if self.session.is_empty() || !self.session.chars().all(|e| e.is_ascii_alphanumeric()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} must hold only letters and digits", SESSION_VAR),
+            ));
+        }
+
+        let url = self.url(day);
+
+        if let Some(rest) = url.strip_prefix("http://") {
+            self.get_http(&url, rest)
+        } else if url.starts_with("https://") {
+            self.get_https(&url)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Unsupported URL: {}", url),
+            ))
+        }
+    }
+}
+
+/// Reads inputs from `cache`, fetching them from `source` and keeping them the first time.
+pub struct Cached<C, P> {
+    pub cache: C,
+    pub source: P,
+}
+
+impl<C: InputCache, P: InputProvider> InputProvider for Cached<C, P> {
+    fn location(&self, day: u8) -> String {
+        self.cache.location(day)
+    }
+
+    fn fetch(&self, day: u8) -> io::Result<String> {
+        match self.cache.fetch(day) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let input = self.source.fetch(day)?;
+                self.cache.store(day, &input)?;
+                Ok(input)
+            }
+            x => x,
+        }
+    }
+}
+
+/// Provider picked from the environment: inputs under `input_dir()`, laid out per user when
+/// `AOC_USER` is set, and downloaded into place when `AOC_SESSION` holds a token.
+pub fn default_provider() -> Box<dyn InputProvider> {
+    let dir = input_dir();
+    let session = env::var(SESSION_VAR).ok().filter(|e| !e.is_empty());
+    let source = |session: String| HttpFetcher {
+        base_url: env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned()),
+        session,
+    };
+
+    match (env::var(USER_VAR).ok(), session) {
+        (Some(user), Some(session)) => Box::new(Cached {
+            cache: UserFiles { dir, user },
+            source: source(session),
+        }),
+        (Some(user), None) => Box::new(UserFiles { dir, user }),
+        (None, Some(session)) => Box::new(Cached {
+            cache: DirectoryCache { dir },
+            source: source(session),
+        }),
+        (None, None) => Box::new(DirectoryCache { dir }),
+    }
+}
+
+#[test]
+fn test_fetch_through_cache() {
+    use std::{net::TcpListener, thread};
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    // Answers a single request, so a second download would fail to connect.
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let count = stream.read(&mut buf).unwrap();
+            request.extend_from_slice(&buf[..count]);
+        }
+
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 12\r\n\r\n1000\n2000\n\n\n")
+            .unwrap();
+
+        String::from_utf8(request).unwrap()
+    });
+
+    let dir = env::temp_dir().join(format!("aoc2022-provider-{}", std::process::id()));
+    let provider = Cached {
+        cache: UserFiles {
+            dir: dir.to_string_lossy().into_owned(),
+            user: "alice".to_owned(),
+        },
+        source: HttpFetcher {
+            base_url: format!("http://{}", address),
+            session: "secret".to_owned(),
+        },
+    };
+
+    assert_eq!(provider.fetch(1).unwrap(), "1000\n2000\n\n\n");
+    assert_eq!(provider.fetch(1).unwrap(), "1000\n2000\n\n\n");
+    assert!(provider.location(1).ends_with("day1/alice.txt"));
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2022/day/1/input HTTP/1.0\r\n"));
+    assert!(request.contains("\r\nCookie: session=secret\r\n"));
+
+    fs::remove_dir_all(dir).unwrap();
+
+    let fetcher = HttpFetcher {
+        base_url: format!("http://{}", address),
+        session: "secret\r\nX-Injected: 1".to_owned(),
+    };
+    assert_eq!(
+        fetcher.fetch(1).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput
+    );
+    assert_eq!(curl_quote(r#"a"b\c"#), r#""a\"b\\c""#);
+}
@@ -0,0 +1,67 @@
+use std::ops::RangeInclusive;
+
+/// Small deterministic pseudo-random generator (SplitMix64), so generated inputs are
+/// reproducible from their seed on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`; `n` must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Uniform value in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = range.end().abs_diff(*range.start()) as u128 + 1;
+
+        range
+            .start()
+            .wrapping_add(((self.next_u64() as u128 * span) >> 64) as i64)
+    }
+
+    pub fn chance(&mut self, percent: u8) -> bool {
+        self.below(100) < percent as usize
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+#[test]
+fn test_deterministic() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+
+    let values = (0..100).map(|_| a.range(-3..=3)).collect::<Vec<i64>>();
+
+    assert_eq!(
+        values,
+        (0..100).map(|_| b.range(-3..=3)).collect::<Vec<i64>>()
+    );
+    assert!(values.iter().all(|e| (-3..=3).contains(e)));
+    assert!((-3..=3).all(|e| values.contains(&e)));
+    assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+}
@@ -0,0 +1,300 @@
+use std::path::Path;
+
+use common::{
+    csv_field, csv_fields, input_name, parse_token, read_input, InputError, Json, ParseError,
+    Solution,
+};
+
+use crate::{Day1, Elf};
+
+pub const CSV_HEADER: &str = "elf,name,calories,label";
+
+/// Inventory in the puzzle's own format; names and labels have no place there and are dropped.
+pub fn to_aoc(elves: &[Elf]) -> String {
+    elves
+        .iter()
+        .map(|e| {
+            e.calories
+                .iter()
+                .map(|e| format!("{}\n", e))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// One row per item under `CSV_HEADER`, with elves numbered from 1.
+pub fn to_csv(elves: &[Elf]) -> String {
+    let mut out = format!("{}\n", CSV_HEADER);
+
+    for (index, elf) in elves.iter().enumerate() {
+        for (item, calories) in elf.calories.iter().enumerate() {
+            out.push_str(&format!(
+                "{},{},{},{}\n",
+                index + 1,
+                csv_field(elf.name.as_deref().unwrap_or("")),
+                calories,
+                csv_field(elf.label(item).unwrap_or(""))
+            ));
+        }
+    }
+
+    out
+}
+
+/// Reads rows of `elf` and `calories` columns, plus optional `name` and `label` ones, in any
+/// order. Rows with the same `elf` value form one elf, in order of first appearance.
+pub fn from_csv(text: &str) -> Result<Vec<Elf>, ParseError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    let Some((header_index, header_line)) = lines.next() else {
+        return Err(ParseError::missing("", "Missing CSV header").on_line(1));
+    };
+
+    let header = csv_fields(header_line).map_err(|e| e.on_line(header_index + 1))?;
+    let column = |name: &str| header.iter().position(|e| e.trim() == name);
+
+    let (Some(elf_column), Some(calories_column)) = (column("elf"), column("calories")) else {
+        return Err(ParseError::at(
+            header_line,
+            header_line,
+            "CSV header needs `elf` and `calories` columns",
+        )
+        .on_line(header_index + 1));
+    };
+    let (name_column, label_column) = (column("name"), column("label"));
+
+    let mut ids: Vec<String> = Vec::new();
+    let mut elves: Vec<Elf> = Vec::new();
+
+    for (index, line) in lines {
+        let on_line = |e: ParseError| e.on_line(index + 1);
+
+        let fields = csv_fields(line).map_err(on_line)?;
+        let field = |column: usize| fields.get(column).map(|e| e.trim()).unwrap_or("");
+        let optional = |column: Option<usize>| {
+            column
+                .map(field)
+                .filter(|e| !e.is_empty())
+                .map(str::to_owned)
+        };
+
+        if fields.len() < header.len() {
+            return Err(on_line(ParseError::missing(
+                line,
+                format!("Expected {} fields", header.len()),
+            )));
+        }
+
+        // Fields are unquoted copies, so point at their first occurrence in the line.
+        let calories = field(calories_column);
+        let token = line
+            .find(calories)
+            .map_or(line, |e| &line[e..e + calories.len()]);
+        let calories = parse_token::<i32>(token, calories)
+            .map_err(|e| on_line(ParseError::at(line, token, e.message)))?;
+
+        let id = field(elf_column);
+        let position = match ids.iter().position(|e| e == id) {
+            Some(x) => x,
+            None => {
+                ids.push(id.to_owned());
+                elves.push(Elf::new(Vec::new()));
+                elves.len() - 1
+            }
+        };
+        let elf = &mut elves[position];
+
+        if let Some(name) = optional(name_column) {
+            match &elf.name {
+                Some(x) if *x != name => {
+                    return Err(on_line(ParseError::at(
+                        line,
+                        line,
+                        format!("Elf {} is already called {}", id, x),
+                    )))
+                }
+                _ => elf.name = Some(name),
+            }
+        }
+
+        elf.calories.push(calories);
+        elf.labels.push(optional(label_column));
+    }
+
+    Ok(elves)
+}
+
+/// `{"elves": [{"name": .., "items": [{"calories": .., "label": ..}, ..]}, ..]}`, leaving out
+/// missing names and labels.
+pub fn to_json(elves: &[Elf]) -> Json {
+    let elves = elves
+        .iter()
+        .map(|elf| {
+            let items = elf
+                .calories
+                .iter()
+                .enumerate()
+                .map(|(item, &calories)| {
+                    let mut fields = vec![("calories".to_owned(), Json::Integer(calories as i64))];
+                    if let Some(label) = elf.label(item) {
+                        fields.push(("label".to_owned(), label.into()));
+                    }
+                    Json::Object(fields)
+                })
+                .collect();
+
+            let mut fields: Vec<(String, Json)> = Vec::new();
+            if let Some(name) = &elf.name {
+                fields.push(("name".to_owned(), name.as_str().into()));
+            }
+            fields.push(("items".to_owned(), Json::Array(items)));
+
+            Json::Object(fields)
+        })
+        .collect();
+
+    Json::Object(vec![("elves".to_owned(), Json::Array(elves))])
+}
+
+/// Reads the layout written by `to_json`; every elf needs at least one item, as in the other
+/// formats.
+pub fn from_json(text: &str) -> Result<Vec<Elf>, ParseError> {
+    let json = Json::parse(text)?;
+
+    // Values carry no position, so errors name the offending path and value instead.
+    let error = |path: String, value: &Json, message: &str| {
+        let text = value.to_string();
+        ParseError::at(&text, &text, format!("{}: {}", path, message))
+    };
+
+    let elves = json
+        .get("elves")
+        .and_then(Json::as_array)
+        .ok_or_else(|| error("elves".to_owned(), &json, "expected an array"))?;
+
+    elves
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let path = format!("elves[{}]", index);
+
+            let name = match value.get("name") {
+                None | Some(Json::Null) => None,
+                Some(x) => Some(
+                    x.as_str()
+                        .ok_or_else(|| error(format!("{}.name", path), x, "expected a string"))?
+                        .to_owned(),
+                ),
+            };
+
+            let items = value
+                .get("items")
+                .and_then(Json::as_array)
+                .ok_or_else(|| error(format!("{}.items", path), value, "expected an array"))?;
+
+            // Other formats have no way to write an elf without items.
+            if items.is_empty() {
+                return Err(error(
+                    format!("{}.items", path),
+                    value,
+                    "expected at least one item",
+                ));
+            }
+
+            let mut elf = Elf::new(Vec::new());
+            elf.name = name;
+
+            for (item, value) in items.iter().enumerate() {
+                let path = format!("{}.items[{}]", path, item);
+
+                let calories = value
+                    .get("calories")
+                    .and_then(Json::as_i64)
+                    .and_then(|e| i32::try_from(e).ok())
+                    .ok_or_else(|| {
+                        error(format!("{}.calories", path), value, "expected an integer")
+                    })?;
+
+                let label = match value.get("label") {
+                    None | Some(Json::Null) => None,
+                    Some(x) => Some(
+                        x.as_str()
+                            .ok_or_else(|| {
+                                error(format!("{}.label", path), x, "expected a string")
+                            })?
+                            .to_owned(),
+                    ),
+                };
+
+                elf.calories.push(calories);
+                elf.labels.push(label);
+            }
+
+            Ok(elf)
+        })
+        .collect()
+}
+
+/// Loads an inventory from `path`, read as CSV or JSON by its extension and in the puzzle's
+/// format otherwise.
+pub fn load_inventory(path: &str) -> Result<Vec<Elf>, InputError> {
+    let text = read_input(path).map_err(|e| InputError::Io(path.to_owned(), e))?;
+
+    let elves = match Path::new(path).extension().and_then(|e| e.to_str()) {
+        Some("csv") => from_csv(&text),
+        Some("json") => from_json(&text),
+        _ => Day1::parse(&text),
+    }
+    .and_then(|e| {
+        if e.is_empty() {
+            Err(ParseError::missing("", "No elves in inventory").on_line(1))
+        } else {
+            Ok(e)
+        }
+    });
+
+    elves.map_err(|e| InputError::Parse(e.in_file(input_name(path))))
+}
+
+#[test]
+fn test_round_trip() {
+    let aoc = "1000\n2000\n\n4000\n";
+
+    let mut elves = Day1::parse(aoc).unwrap();
+
+    assert_eq!(to_aoc(&from_csv(&to_csv(&elves)).unwrap()), aoc);
+    assert_eq!(
+        to_aoc(&from_json(&to_json(&elves).to_string()).unwrap()),
+        aoc
+    );
+
+    elves[0].name = Some("Dasher, \"the fast\"".to_owned());
+    elves[0].labels[1] = Some("apple pie".to_owned());
+
+    let csv = to_csv(&elves);
+    assert_eq!(
+        csv,
+        "elf,name,calories,label\n\
+         1,\"Dasher, \"\"the fast\"\"\",1000,\n\
+         1,\"Dasher, \"\"the fast\"\"\",2000,apple pie\n\
+         2,,4000,\n"
+    );
+    assert_eq!(from_csv(&csv).unwrap(), elves);
+    assert_eq!(from_json(&to_json(&elves).pretty()).unwrap(), elves);
+
+    let spreadsheet = "calories,elf\r\n300,b\r\n100,a\r\n200,b\r\n";
+    let elves = from_csv(spreadsheet).unwrap();
+    assert_eq!(to_aoc(&elves), "300\n200\n\n100\n");
+
+    let error = from_csv("elf,calories\n1,10\n1,ten\n").unwrap_err();
+    assert_eq!(error.line, 3);
+    let json = r#"{"elves": [{"items": [{"calories": 1}]}, {"name": "Vixen", "items": []}]}"#;
+    let error = from_json(json).unwrap_err();
+    assert!(error
+        .message
+        .starts_with("elves[1].items: expected at least one item"));
+}
@@ -0,0 +1,182 @@
+use crate::Elf;
+
+impl Elf {
+    pub fn total(&self) -> i64 {
+        self.calories.iter().map(|&e| e as i64).sum()
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.calories.len()
+    }
+
+    pub fn largest_item(&self) -> Option<i32> {
+        self.calories.iter().copied().max()
+    }
+}
+
+/// Elf at `index` in the input, placed by total calories.
+///
+/// Tied elves share a `rank`, and the next rank skips past them (1, 2, 2, 4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ranked {
+    pub index: usize,
+    pub rank: usize,
+    pub total: i64,
+}
+
+/// Totals from `start` (inclusive) to `end` (exclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: i64,
+    pub end: i64,
+    pub count: usize,
+}
+
+/// Statistics over the calorie totals of a list of elves.
+pub struct Inventory {
+    totals: Vec<i64>,
+    sorted: Vec<i64>,
+}
+
+impl Inventory {
+    pub fn new(elves: &[Elf]) -> Self {
+        let totals = elves.iter().map(Elf::total).collect::<Vec<i64>>();
+
+        let mut sorted = totals.clone();
+        sorted.sort_unstable();
+
+        Inventory { totals, sorted }
+    }
+
+    pub fn len(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// The `n` elves carrying the most, plus any elves tied with the last of them.
+    pub fn top(&self, n: usize) -> Vec<Ranked> {
+        let mut order = (0..self.totals.len()).collect::<Vec<usize>>();
+        order.sort_by_key(|&e| (-self.totals[e], e));
+
+        let mut ranked: Vec<Ranked> = Vec::new();
+
+        for (position, index) in order.into_iter().enumerate() {
+            let total = self.totals[index];
+
+            let rank = match ranked.last() {
+                Some(previous) if previous.total == total => previous.rank,
+                _ if position >= n => break,
+                _ => position + 1,
+            };
+
+            ranked.push(Ranked { index, rank, total });
+        }
+
+        ranked
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.totals.iter().sum::<i64>() as f64 / self.len() as f64)
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// Percentile of the totals, interpolating linearly between the closest ranks.
+    pub fn percentile(&self, percent: f64) -> Option<f64> {
+        if self.is_empty() || !(0.0..=100.0).contains(&percent) {
+            return None;
+        }
+
+        let position = percent / 100.0 * (self.len() - 1) as f64;
+        let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+        let fraction = position - lower as f64;
+
+        Some(self.sorted[lower] as f64 * (1.0 - fraction) + self.sorted[upper] as f64 * fraction)
+    }
+
+    /// Counts of totals in buckets `width` wide, in order, leaving out empty buckets.
+    pub fn histogram(&self, width: i64) -> Vec<Bucket> {
+        let width = width.max(1);
+        let mut buckets: Vec<Bucket> = Vec::new();
+
+        for total in &self.sorted {
+            let start = total.div_euclid(width) * width;
+
+            match buckets.last_mut() {
+                Some(x) if x.start == start => x.count += 1,
+                _ => buckets.push(Bucket {
+                    start,
+                    end: start.saturating_add(width),
+                    count: 1,
+                }),
+            }
+        }
+
+        buckets
+    }
+}
+
+#[test]
+fn test_inventory() {
+    let elves = [
+        vec![1000, 2000],
+        vec![4000],
+        vec![3000],
+        vec![500, 2500],
+        vec![],
+    ]
+    .into_iter()
+    .map(Elf::new)
+    .collect::<Vec<Elf>>();
+
+    let inventory = Inventory::new(&elves);
+
+    let top = inventory
+        .top(2)
+        .iter()
+        .map(|e| (e.index, e.rank, e.total))
+        .collect::<Vec<(usize, usize, i64)>>();
+    assert_eq!(
+        top,
+        vec![(1, 1, 4000), (0, 2, 3000), (2, 2, 3000), (3, 2, 3000)]
+    );
+
+    assert_eq!(inventory.mean(), Some(2600.0));
+    assert_eq!(inventory.median(), Some(3000.0));
+    assert_eq!(inventory.percentile(10.0), Some(1200.0));
+    assert_eq!(
+        inventory
+            .histogram(2000)
+            .iter()
+            .map(|e| (e.start, e.count))
+            .collect::<Vec<(i64, usize)>>(),
+        vec![(0, 1), (2000, 3), (4000, 1)]
+    );
+    assert_eq!(
+        inventory
+            .histogram(1)
+            .iter()
+            .map(|e| (e.start, e.count))
+            .collect::<Vec<(i64, usize)>>(),
+        vec![(0, 1), (3000, 3), (4000, 1)]
+    );
+
+    assert_eq!(
+        (elves[3].item_count(), elves[3].largest_item()),
+        (2, Some(2500))
+    );
+    assert_eq!(elves[4].largest_item(), None);
+
+    assert_eq!(crate::get_most_calories(&elves), 4000);
+    assert_eq!(crate::get_most_calories(&[]), 0);
+    assert!(Inventory::new(&[]).histogram(10).is_empty());
+}
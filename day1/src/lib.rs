@@ -0,0 +1,92 @@
+use common::{load, parse_groups, parse_token, Answer, InputError, ParseError, Rng, Solution};
+
+mod exchange;
+mod inventory;
+mod stream;
+mod teams;
+
+pub use exchange::{from_csv, from_json, load_inventory, to_aoc, to_csv, to_json, CSV_HEADER};
+pub use inventory::{Bucket, Inventory, Ranked};
+pub use stream::{load_top_k, top_k_totals, TopK};
+pub use teams::{
+    max_total, partition, partition_exact, partition_karmarkar_karp, partition_lpt, Team, TeamList,
+    EXACT_LIMIT,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub name: Option<String>,
+    pub calories: Vec<i32>,
+    /// Label of each entry in `calories`, when known.
+    pub labels: Vec<Option<String>>,
+}
+
+impl Elf {
+    pub fn new(calories: Vec<i32>) -> Self {
+        Elf {
+            name: None,
+            labels: vec![None; calories.len()],
+            calories,
+        }
+    }
+
+    pub fn label(&self, item: usize) -> Option<&str> {
+        self.labels.get(item)?.as_deref()
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let groups = parse_groups(input, |line| parse_token::<i32>(line, line.trim()))?;
+
+        if groups.is_empty() {
+            return Err(ParseError::missing("", "No calorie entries").on_line(1));
+        }
+
+        Ok(groups.into_iter().map(Elf::new).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        get_most_calories(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        get_top_three_calories(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                (0..rng.range(1..=15))
+                    .map(|_| format!("{}\n", rng.range(1000..=70000)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+pub fn load_elves(path: &str) -> Result<Vec<Elf>, InputError> {
+    load::<Day1>(path)
+}
+
+/// Total of the elf carrying the most, or 0 without elves.
+pub fn get_most_calories(elves: &[Elf]) -> i64 {
+    elves.iter().map(Elf::total).max().unwrap_or(0)
+}
+
+pub fn get_top_three_calories(elves: &[Elf]) -> i64 {
+    let mut top = TopK::new(3);
+
+    for elf in elves {
+        top.push(elf.total());
+    }
+
+    top.into_sorted_vec().iter().sum()
+}
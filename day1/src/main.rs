@@ -1,59 +1,118 @@
-use std::{
-    collections::BinaryHeap,
-    fs::File,
-    io::{BufRead, BufReader},
+use std::{env, process};
+
+use common::{default_input_path, Solution};
+use day1::{
+    get_most_calories, get_top_three_calories, load_inventory, load_top_k, partition, Day1,
+    Inventory, TeamList,
 };
 
-struct Elf {
-    calories: Vec<i32>,
-}
+fn main() {
+    // `--stream[=K]` reads the input line by line and prints only the top K totals (3 by
+    // default), for logs too large to hold in memory.
+    let (flags, args): (Vec<String>, Vec<String>) =
+        env::args().skip(1).partition(|e| e.starts_with("--"));
 
-fn load_elves(path: &'static str) -> Vec<Elf> {
-    let file = File::open(path).unwrap();
-    let reader = BufReader::new(file);
+    let mut stream = None;
 
-    let mut elves: Vec<Elf> = Vec::new();
+    for flag in &flags {
+        if let Some(x) = flag.strip_prefix("--stream=") {
+            let k = x.parse::<usize>().ok().filter(|&e| e > 0);
+            stream = Some(k.unwrap_or_else(|| {
+                eprintln!("Invalid elf count: {}", x);
+                process::exit(2);
+            }));
+        } else if flag == "--stream" {
+            stream = Some(3);
+        } else {
+            eprintln!("Unknown option: {}", flag);
+            process::exit(2);
+        }
+    }
 
-    let mut temp_vector: Vec<i32> = Vec::new();
-    for line_result in reader.lines() {
-        if let Ok(line) = line_result {
-            if line != "" {
-                let calories = i32::from_str_radix(line.as_str(), 10).unwrap();
+    let path = args
+        .first()
+        .cloned()
+        .unwrap_or_else(|| default_input_path(Day1::DAY));
 
-                temp_vector.push(calories);
-            } else {
-                elves.push(Elf {
-                    calories: temp_vector,
-                });
+    let team_count = args.get(1).map(|e| {
+        e.parse::<usize>()
+            .ok()
+            .filter(|&e| e > 0)
+            .unwrap_or_else(|| {
+                eprintln!("Invalid team count: {}", e);
+                process::exit(2);
+            })
+    });
 
-                temp_vector = Vec::new();
-            }
+    if let Some(k) = stream {
+        if team_count.is_some() {
+            eprintln!("Teams need the whole inventory and cannot be used with --stream");
+            process::exit(2);
         }
-    }
 
-    elves
-}
+        let totals = load_top_k(&path, k).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        });
 
-fn main() {
-    let elves = load_elves("./day1/input.txt");
+        for (index, total) in totals.iter().enumerate() {
+            println!("#{}: {} calories", index + 1, total);
+        }
+        println!(
+            "Top {} elves carrying the most calories carry {} calories",
+            totals.len(),
+            totals.iter().sum::<i64>()
+        );
+
+        return;
+    }
+
+    let elves = load_inventory(&path).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
 
     println!(
         "Elf carrying the most calories carries {} calories",
-        elves
-            .iter()
-            .map(|e| e.calories.iter().sum::<i32>())
-            .max()
-            .unwrap()
+        get_most_calories(&elves)
     );
 
     println!(
         "Top three elves carrying the most calories carry {} calories",
-        elves
-            .iter()
-            .map(|e| e.calories.iter().sum::<i32>())
-            .collect::<BinaryHeap<i32>>()
-            .iter()
-            .take(3)
-            .sum::<i32>()
+        get_top_three_calories(&elves)
     );
+
+    let inventory = Inventory::new(&elves);
+
+    for ranked in inventory.top(3) {
+        let elf = &elves[ranked.index];
+
+        let name = elf
+            .name
+            .as_ref()
+            .map_or(String::new(), |e| format!(" ({})", e));
+
+        println!(
+            "#{} elf {}{}: {} calories in {} items, largest {}",
+            ranked.rank,
+            ranked.index + 1,
+            name,
+            ranked.total,
+            elf.item_count(),
+            elf.largest_item().unwrap_or(0)
+        );
+    }
+
+    if let (Some(mean), Some(median)) = (inventory.mean(), inventory.median()) {
+        println!(
+            "{} elves carry {:.1} calories on average, {:.1} median",
+            inventory.len(),
+            mean,
+            median
+        );
+    }
+
+    if let Some(count) = team_count {
+        print!("{}", TeamList(&partition(&elves, count)));
+    }
 }
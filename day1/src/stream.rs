@@ -0,0 +1,122 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs::File,
+    io::{self, BufRead, BufReader},
+};
+
+use common::{input_name, parse_token, InputError, ParseError};
+
+/// The `k` largest values pushed so far, kept in a min-heap of at most `k` entries.
+pub struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<i64>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, value: i64) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(value));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if value > smallest.0 {
+                *smallest = Reverse(value);
+            }
+        }
+    }
+
+    /// Kept values, largest first.
+    pub fn into_sorted_vec(self) -> Vec<i64> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|e| e.0)
+            .collect()
+    }
+}
+
+/// Totals of the `k` elves carrying the most in `reader`, largest first.
+///
+/// Reads one line at a time, so memory stays bounded by `k` and the longest line. Groups
+/// follow the same rules as `parse_groups`; I/O errors are reported against `<input>`.
+pub fn top_k_totals(mut reader: impl BufRead, k: usize) -> Result<Vec<i64>, InputError> {
+    let mut top = TopK::new(k);
+    let mut total: Option<i64> = None;
+
+    let mut buf = String::new();
+    let mut number = 0;
+
+    loop {
+        buf.clear();
+        let read = reader
+            .read_line(&mut buf)
+            .map_err(|e| InputError::Io("<input>".to_owned(), e))?;
+
+        if read == 0 {
+            break;
+        }
+
+        number += 1;
+        let line = buf.trim_end_matches(['\n', '\r']);
+
+        if line.trim().is_empty() {
+            if let Some(x) = total.take() {
+                top.push(x);
+            }
+        } else if !line.trim_start().starts_with('#') {
+            let calories = parse_token::<i64>(line, line.trim()).map_err(|e| e.on_line(number))?;
+
+            total = Some(total.unwrap_or(0).checked_add(calories).ok_or_else(|| {
+                ParseError::at(line, line.trim(), "Calorie total overflows").on_line(number)
+            })?);
+        }
+    }
+
+    if let Some(x) = total {
+        top.push(x);
+    }
+
+    Ok(top.into_sorted_vec())
+}
+
+/// Streams the file at `path` (or stdin for `-`) through `top_k_totals`.
+pub fn load_top_k(path: &str, k: usize) -> Result<Vec<i64>, InputError> {
+    let result = if path == "-" {
+        top_k_totals(io::stdin().lock(), k)
+    } else {
+        let file = File::open(path).map_err(|e| InputError::Io(path.to_owned(), e))?;
+
+        top_k_totals(BufReader::new(file), k)
+    };
+
+    result.map_err(|e| match e {
+        InputError::Io(_, e) => InputError::Io(path.to_owned(), e),
+        InputError::Parse(e) => InputError::Parse(e.in_file(input_name(path))),
+    })
+}
+
+#[test]
+fn test_top_k_totals() {
+    let input = "1000\r\n2000\r\n\r\n\r\n4000\n# spare\n\n5000\n6000\n\n7000\n8000\n9000";
+
+    assert_eq!(
+        top_k_totals(input.as_bytes(), 2).unwrap(),
+        vec![24000, 11000]
+    );
+    assert_eq!(top_k_totals(input.as_bytes(), 0).unwrap(), vec![]);
+    assert_eq!(top_k_totals(input.as_bytes(), 9).unwrap().len(), 4);
+
+    let overflow = format!("{}\n1\n", i64::MAX);
+    match top_k_totals(overflow.as_bytes(), 1) {
+        Err(InputError::Parse(e)) => {
+            assert_eq!((e.line, e.message.as_str()), (2, "Calorie total overflows"))
+        }
+        _ => panic!("expected an overflow error"),
+    }
+}
@@ -0,0 +1,288 @@
+use std::{collections::BinaryHeap, fmt};
+
+use crate::Elf;
+
+/// Inputs up to this many elves are partitioned exactly by `partition`.
+pub const EXACT_LIMIT: usize = 16;
+
+/// Elves, by index in the input, sharing the load of one team.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Team {
+    pub members: Vec<usize>,
+    pub total: i64,
+}
+
+impl Team {
+    fn new() -> Self {
+        Team {
+            members: Vec::new(),
+            total: 0,
+        }
+    }
+
+    fn add(&mut self, index: usize, total: i64) {
+        self.members.push(index);
+        self.total += total;
+    }
+}
+
+/// Heaviest team total of `teams`.
+pub fn max_total(teams: &[Team]) -> i64 {
+    teams.iter().map(|e| e.total).max().unwrap_or(0)
+}
+
+/// Elf indices with their totals, heaviest first.
+fn sorted_totals(elves: &[Elf]) -> Vec<(usize, i64)> {
+    let mut totals = elves
+        .iter()
+        .map(Elf::total)
+        .enumerate()
+        .collect::<Vec<(usize, i64)>>();
+    totals.sort_by_key(|&(index, total)| (-total, index));
+
+    totals
+}
+
+/// Heaviest teams first, members in input order.
+fn normalize(mut teams: Vec<Team>) -> Vec<Team> {
+    for team in &mut teams {
+        team.members.sort_unstable();
+    }
+    teams.sort_by_key(|e| (-e.total, e.members.first().copied()));
+
+    teams
+}
+
+/// Longest processing time: each elf, heaviest first, joins the currently lightest team.
+pub fn partition_lpt(elves: &[Elf], k: usize) -> Vec<Team> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut teams = vec![Team::new(); k];
+
+    for (index, total) in sorted_totals(elves) {
+        let lightest = (0..k).min_by_key(|&e| (teams[e].total, e)).unwrap();
+        teams[lightest].add(index, total);
+    }
+
+    normalize(teams)
+}
+
+/// Multiway Karmarkar-Karp differencing: repeatedly merges the two partial partitions with
+/// the widest spread, pairing the heaviest teams of one with the lightest of the other.
+pub fn partition_karmarkar_karp(elves: &[Elf], k: usize) -> Vec<Team> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    // Every partial partition keeps its teams sorted heaviest first.
+    let mut partials: Vec<Vec<Team>> = Vec::new();
+    let mut heap: BinaryHeap<(i64, usize)> = BinaryHeap::new();
+
+    let spread = |teams: &[Team]| teams[0].total - teams[teams.len() - 1].total;
+
+    for (index, total) in sorted_totals(elves) {
+        let mut teams = vec![Team::new(); k];
+        teams[0].add(index, total);
+
+        heap.push((spread(&teams), partials.len()));
+        partials.push(teams);
+    }
+
+    while heap.len() > 1 {
+        let (_, a) = heap.pop().unwrap();
+        let (_, b) = heap.pop().unwrap();
+
+        let first = std::mem::take(&mut partials[a]);
+        let second = std::mem::take(&mut partials[b]);
+
+        let mut merged = first
+            .into_iter()
+            .zip(second.into_iter().rev())
+            .map(|(mut x, y)| {
+                x.members.extend(y.members);
+                x.total += y.total;
+                x
+            })
+            .collect::<Vec<Team>>();
+        merged.sort_by_key(|e| -e.total);
+
+        heap.push((spread(&merged), partials.len()));
+        partials.push(merged);
+    }
+
+    match heap.pop() {
+        Some((_, index)) => normalize(std::mem::take(&mut partials[index])),
+        None => vec![Team::new(); k],
+    }
+}
+
+/// Optimal partition by branch and bound; exponential, meant for small inputs.
+pub fn partition_exact(elves: &[Elf], k: usize) -> Vec<Team> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let totals = sorted_totals(elves);
+
+    let mut best = partition_lpt(elves, k);
+    let mut best_max = max_total(&best);
+
+    // Negative totals, sorted last, can still lighten a team: `negative_rest[i]` is the sum
+    // of those from `totals[i]` on.
+    let mut negative_rest = vec![0; totals.len() + 1];
+    for (index, &(_, total)) in totals.iter().enumerate().rev() {
+        negative_rest[index] = negative_rest[index + 1] + total.min(0);
+    }
+
+    // No team can carry less than an equal share of the total, or than the heaviest elf
+    // with every negative total added.
+    let sum = totals.iter().map(|e| e.1).sum::<i64>();
+    let lower_bound = (sum + k as i64 - 1)
+        .div_euclid(k as i64)
+        .max(totals.first().map_or(0, |e| e.1 + negative_rest[0]));
+
+    let mut teams = vec![Team::new(); k];
+
+    search(
+        &totals,
+        &negative_rest,
+        0,
+        &mut teams,
+        &mut best,
+        &mut best_max,
+        lower_bound,
+    );
+
+    normalize(best)
+}
+
+fn search(
+    totals: &[(usize, i64)],
+    negative_rest: &[i64],
+    next: usize,
+    teams: &mut [Team],
+    best: &mut Vec<Team>,
+    best_max: &mut i64,
+    lower_bound: i64,
+) {
+    if *best_max <= lower_bound {
+        return;
+    }
+
+    let Some(&(index, total)) = totals.get(next) else {
+        let current = max_total(teams);
+        if current < *best_max {
+            *best_max = current;
+            *best = teams.to_vec();
+        }
+        return;
+    };
+
+    for team in 0..teams.len() {
+        if teams[team].total + total + negative_rest[next + 1] >= *best_max {
+            continue;
+        }
+
+        // Teams with the same load are interchangeable, so only try the first of them.
+        if teams[..team].iter().any(|e| e.total == teams[team].total) {
+            continue;
+        }
+
+        teams[team].add(index, total);
+        search(
+            totals,
+            negative_rest,
+            next + 1,
+            teams,
+            best,
+            best_max,
+            lower_bound,
+        );
+        teams[team].members.pop();
+        teams[team].total -= total;
+    }
+}
+
+/// Exact partition for up to `EXACT_LIMIT` elves, otherwise the better of LPT and
+/// Karmarkar-Karp.
+pub fn partition(elves: &[Elf], k: usize) -> Vec<Team> {
+    if elves.len() <= EXACT_LIMIT {
+        return partition_exact(elves, k);
+    }
+
+    let lpt = partition_lpt(elves, k);
+    let kk = partition_karmarkar_karp(elves, k);
+
+    if max_total(&kk) < max_total(&lpt) {
+        kk
+    } else {
+        lpt
+    }
+}
+
+/// Lists every team with its members, numbered from 1 as in the input, and its total.
+pub struct TeamList<'a>(pub &'a [Team]);
+
+impl fmt::Display for TeamList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (number, team) in self.0.iter().enumerate() {
+            let members = team
+                .members
+                .iter()
+                .map(|e| (e + 1).to_string())
+                .collect::<Vec<String>>();
+
+            writeln!(
+                f,
+                "Team {}: {} calories, elves {}",
+                number + 1,
+                team.total,
+                members.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_partition() {
+    let elves = [8, 7, 6, 5, 4]
+        .into_iter()
+        .map(|e| Elf::new(vec![e * 1000]))
+        .collect::<Vec<Elf>>();
+
+    let exact = partition_exact(&elves, 2);
+    assert_eq!(max_total(&exact), 15000);
+    assert_eq!(max_total(&partition_lpt(&elves, 2)), 17000);
+    assert_eq!(max_total(&partition_karmarkar_karp(&elves, 2)), 16000);
+
+    for teams in [
+        exact,
+        partition_lpt(&elves, 3),
+        partition_karmarkar_karp(&elves, 3),
+        partition(&elves, 7),
+    ] {
+        let mut members = teams
+            .iter()
+            .flat_map(|e| e.members.iter().copied())
+            .collect::<Vec<usize>>();
+        members.sort_unstable();
+
+        assert_eq!(members, vec![0, 1, 2, 3, 4]);
+        assert_eq!(teams.iter().map(|e| e.total).sum::<i64>(), 30000);
+    }
+
+    assert_eq!(
+        TeamList(&partition(&elves, 2)).to_string(),
+        "Team 1: 15000 calories, elves 1, 2\nTeam 2: 15000 calories, elves 3, 4, 5\n"
+    );
+
+    let elves = [10, 10, 10, -10]
+        .into_iter()
+        .map(|e| Elf::new(vec![e]))
+        .collect::<Vec<Elf>>();
+    assert_eq!(max_total(&partition_exact(&elves, 2)), 10);
+}
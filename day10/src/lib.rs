@@ -0,0 +1,125 @@
+use std::str::FromStr;
+
+use common::{load, parse_lines, parse_token, Answer, InputError, ParseError, Rng, Solution};
+
+pub enum Operation {
+    Noop,
+    AddX(i32),
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<_> = s.split(' ').collect();
+
+        if tokens.len() == 1 && tokens[0] == "noop" {
+            Ok(Operation::Noop)
+        } else if tokens.len() == 2 && tokens[0] == "addx" {
+            Ok(Operation::AddX(parse_token::<i32>(s, tokens[1])?))
+        } else {
+            Err(ParseError::at(s, s, "Unknown operation"))
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Operation::from_str)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (signal_strengths, _) = simulate(input, &[20, 60, 100, 140, 180, 220]);
+
+        signal_strengths.iter().sum::<i32>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (_, crt) = simulate(input, &[]);
+
+        crt.into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        let (mut lines, mut cycles) = (0, 0);
+
+        // Keep going until the whole CRT is drawn.
+        while lines < size.max(1) || cycles < 240 {
+            if rng.chance(60) {
+                out.push_str(&format!("addx {}\n", rng.range(-20..=20)));
+                cycles += 2;
+            } else {
+                out.push_str("noop\n");
+                cycles += 1;
+            }
+            lines += 1;
+        }
+
+        out
+    }
+}
+
+pub fn load_operations(path: &str) -> Result<Vec<Operation>, InputError> {
+    load::<Day10>(path)
+}
+
+pub fn simulate(operations: &[Operation], coi: &[i32]) -> (Vec<i32>, String) {
+    let mut x_register: i32 = 1;
+    let mut instruction_pointer: usize = 0;
+    let mut cycle = 0;
+
+    // Value of an `addx` in its first cycle, added at the end of its second.
+    let mut pending: Option<i32> = None;
+
+    let mut signal_strengths: Vec<i32> = Vec::new();
+    let mut crt = String::new();
+
+    while instruction_pointer != operations.len() {
+        if x_register.abs_diff(cycle % 40) <= 1 {
+            crt.push('#');
+        } else {
+            crt.push('.');
+        }
+
+        if (cycle + 1) % 40 == 0 {
+            crt.push('\n');
+        }
+        cycle += 1;
+
+        if coi.contains(&cycle) {
+            signal_strengths.push(cycle * x_register);
+        }
+
+        if let Some(x) = pending.take() {
+            x_register += x;
+            instruction_pointer += 1;
+        } else {
+            match operations[instruction_pointer] {
+                Operation::Noop => {
+                    instruction_pointer += 1;
+                }
+                Operation::AddX(x) => {
+                    pending = Some(x);
+                }
+            }
+        }
+    }
+
+    (signal_strengths, crt)
+}
+
+#[test]
+fn test_addx_zero() {
+    let operations = Day10::parse("addx 0\nnoop\naddx 2\n").unwrap();
+    let (signal_strengths, crt) = simulate(&operations, &[1, 2, 3, 4, 5]);
+
+    assert_eq!(signal_strengths, vec![1, 2, 3, 4, 5]);
+    assert_eq!(crt, "###..");
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+
+use crate::{Guide, RoundOutcome, Scoring, Shape};
+
+const PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+const OUTCOMES: [RoundOutcome; 3] = [RoundOutcome::Lose, RoundOutcome::Draw, RoundOutcome::Win];
+
+/// What `X`, `Y` and `Z` in the second column of a guide stand for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Meaning {
+    Shape([Shape; 3]),
+    Outcome([RoundOutcome; 3]),
+}
+
+impl Meaning {
+    /// Every bijection of `X`, `Y` and `Z` to shapes, then to outcomes; the puzzle's two
+    /// readings come first.
+    pub fn all() -> Vec<Meaning> {
+        let shapes = PERMUTATIONS.map(|e| Meaning::Shape(e.map(|e| Shape::ALL[e])));
+        let outcomes = PERMUTATIONS.map(|e| Meaning::Outcome(e.map(|e| OUTCOMES[e])));
+
+        shapes.into_iter().chain(outcomes).collect()
+    }
+
+    /// Shape played against `opponent` for the letter at `letter` (0 for `X`).
+    pub fn decode(&self, opponent: Shape, letter: usize) -> Shape {
+        match self {
+            Meaning::Shape(x) => x[letter],
+            Meaning::Outcome(x) => opponent.get_shape(x[letter]),
+        }
+    }
+}
+
+impl fmt::Display for Meaning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values = match self {
+            Meaning::Shape(x) => x.map(|e| format!("{:?}", e)),
+            Meaning::Outcome(x) => x.map(|e| format!("{:?}", e)),
+        };
+
+        write!(f, "X={} Y={} Z={}", values[0], values[1], values[2])
+    }
+}
+
+/// Result of playing a guide under one meaning.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Decoding {
+    pub meaning: Meaning,
+    pub score: i64,
+    pub wins: usize,
+    pub rounds: usize,
+}
+
+impl Decoding {
+    pub fn win_rate(&self) -> f64 {
+        if self.rounds == 0 {
+            return 0.0;
+        }
+
+        self.wins as f64 / self.rounds as f64
+    }
+}
+
+pub fn decode(guide: &Guide, meaning: Meaning, scoring: &Scoring) -> Decoding {
+    let mut decoding = Decoding {
+        meaning,
+        score: 0,
+        wins: 0,
+        rounds: guide.len(),
+    };
+
+    for &(opponent, (letter, _)) in guide {
+        // The part 1 reading is a bijection, so the shape gives back the letter.
+        let me = meaning.decode(opponent, letter.index());
+        decoding.score += scoring.round(opponent, me) as i64;
+
+        if Scoring::outcome(opponent, me) == RoundOutcome::Win {
+            decoding.wins += 1;
+        }
+    }
+
+    decoding
+}
+
+/// The guide played under every meaning, in the order of `Meaning::all`.
+pub fn decodings(guide: &Guide, scoring: &Scoring) -> Vec<Decoding> {
+    Meaning::all()
+        .into_iter()
+        .map(|e| decode(guide, e, scoring))
+        .collect()
+}
+
+/// Decoding scoring closest to `target`, the first of `decodings` on ties.
+pub fn closest_to_score(guide: &Guide, target: i64, scoring: &Scoring) -> Decoding {
+    decodings(guide, scoring)
+        .into_iter()
+        .min_by_key(|e| (e.score - target).abs())
+        .unwrap()
+}
+
+/// Decoding winning the share of rounds closest to `rate`, the first of `decodings` on ties.
+pub fn closest_to_win_rate(guide: &Guide, rate: f64, scoring: &Scoring) -> Decoding {
+    decodings(guide, scoring)
+        .into_iter()
+        .min_by(|a, b| {
+            (a.win_rate() - rate)
+                .abs()
+                .total_cmp(&(b.win_rate() - rate).abs())
+        })
+        .unwrap()
+}
+
+/// Lists decodings with their score and win rate.
+pub struct DecodingTable<'a>(pub &'a [Decoding]);
+
+impl fmt::Display for DecodingTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for decoding in self.0 {
+            writeln!(
+                f,
+                "{:<30} score {:>8}, won {:>5.1}%",
+                decoding.meaning.to_string(),
+                decoding.score,
+                decoding.win_rate() * 100.0
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_decodings() {
+    use common::Solution;
+
+    let guide = crate::Day2::parse("A Y\nB X\nC Z\n").unwrap();
+    let scoring = Scoring::default();
+    let all = decodings(&guide, &scoring);
+
+    assert_eq!(all.len(), 12);
+    assert_eq!((all[0].score, all[6].score), (15, 12));
+    assert_eq!(all[0].meaning.to_string(), "X=Rock Y=Paper Z=Scissors");
+    assert_eq!(all[6].meaning.to_string(), "X=Lose Y=Draw Z=Win");
+
+    assert_eq!(
+        closest_to_score(&guide, 12, &scoring).meaning,
+        all[6].meaning
+    );
+
+    let best = closest_to_win_rate(&guide, 1.0, &scoring);
+    assert_eq!(
+        best.meaning,
+        Meaning::Shape([Shape::Scissors, Shape::Paper, Shape::Rock])
+    );
+    assert_eq!((best.score, best.wins), (24, 3));
+}
@@ -0,0 +1,262 @@
+use std::sync::OnceLock;
+
+use common::{parse_lines, token, ParseError};
+
+use crate::{RoundOutcome, Scoring};
+
+pub const WIN_POINTS: i32 = 6;
+pub const DRAW_POINTS: i32 = 3;
+pub const LOSE_POINTS: i32 = 0;
+
+/// How the second column of a guide is read.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Reading {
+    /// The weapon to play, as in part 1.
+    Weapon,
+    /// `X`, `Y` or `Z` to lose, draw or win, as in part 2.
+    Outcome,
+}
+
+/// A game of weapons where every pair of different weapons has exactly one winner.
+///
+/// Weapons are referred to by index. In guides the opponent plays `A`, `B`, ... and the
+/// response plays the same number of letters ending in `Z`, so `X`, `Y` and `Z` for three
+/// weapons; names work in both columns too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    /// `beats[a][b]` when weapon `a` beats weapon `b`.
+    beats: Vec<Vec<bool>>,
+    scoring: Scoring,
+}
+
+impl Game {
+    pub fn new(
+        names: Vec<String>,
+        scores: Vec<i32>,
+        beats: &[(usize, usize)],
+    ) -> Result<Self, String> {
+        let n = names.len();
+
+        if n == 0 {
+            return Err("A game needs at least one weapon".to_owned());
+        }
+        if scores.len() != n {
+            return Err(format!("Expected {} scores, found {}", n, scores.len()));
+        }
+        for (index, name) in names.iter().enumerate() {
+            if name.is_empty() || names[..index].iter().any(|e| e.eq_ignore_ascii_case(name)) {
+                return Err(format!("Invalid or repeated weapon name `{}`", name));
+            }
+        }
+
+        let mut table = vec![vec![false; n]; n];
+
+        for &(a, b) in beats {
+            if a >= n || b >= n || a == b {
+                return Err(format!("Invalid beats pair ({}, {})", a, b));
+            }
+            table[a][b] = true;
+        }
+
+        for a in 0..n {
+            for b in a + 1..n {
+                if table[a][b] == table[b][a] {
+                    return Err(format!(
+                        "Exactly one of {} and {} must beat the other",
+                        names[a], names[b]
+                    ));
+                }
+            }
+        }
+
+        Ok(Game {
+            names,
+            beats: table,
+            scoring: Scoring::new(scores),
+        })
+    }
+
+    /// Balanced game of an odd number of weapons, scored 1, 2, ... in order, where each
+    /// weapon beats the ones an odd number of places before it, wrapping around.
+    pub fn cyclic(names: Vec<String>) -> Result<Self, String> {
+        let n = names.len();
+
+        if n.is_multiple_of(2) {
+            return Err(format!(
+                "A balanced game needs an odd number of weapons, not {}",
+                n
+            ));
+        }
+
+        let beats = (0..n)
+            .flat_map(|a| (0..n).map(move |b| (a, b)))
+            .filter(|&(a, b)| (a + n - b) % n % 2 == 1)
+            .collect::<Vec<(usize, usize)>>();
+
+        Game::new(names, (1..=n as i32).collect(), &beats)
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Game::cyclic(vec!["Rock".into(), "Paper".into(), "Scissors".into()]).unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Game::cyclic(vec![
+            "Rock".into(),
+            "Paper".into(),
+            "Scissors".into(),
+            "Spock".into(),
+            "Lizard".into(),
+        ])
+        .unwrap()
+    }
+
+    /// Scores the game with `scoring`, which must give points to every weapon.
+    pub fn with_scoring(mut self, scoring: Scoring) -> Result<Self, String> {
+        if scoring.weapons.len() != self.len() {
+            return Err(format!(
+                "Expected points for {} weapons, found {}",
+                self.len(),
+                scoring.weapons.len()
+            ));
+        }
+
+        self.scoring = scoring;
+        Ok(self)
+    }
+
+    /// Shared rock-paper-scissors game behind `Shape`.
+    pub(crate) fn classic() -> &'static Game {
+        static GAME: OnceLock<Game> = OnceLock::new();
+
+        GAME.get_or_init(Game::rock_paper_scissors)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn name(&self, weapon: usize) -> &str {
+        &self.names[weapon]
+    }
+
+    pub fn scoring(&self) -> &Scoring {
+        &self.scoring
+    }
+
+    pub fn outcome(&self, me: usize, opponent: usize) -> RoundOutcome {
+        if self.beats[me][opponent] {
+            RoundOutcome::Win
+        } else if self.beats[opponent][me] {
+            RoundOutcome::Lose
+        } else {
+            RoundOutcome::Draw
+        }
+    }
+
+    pub fn score(&self, me: usize, opponent: usize) -> i32 {
+        self.scoring.outcome_points(self.outcome(me, opponent)) + self.scoring.weapon_points(me)
+    }
+
+    /// Highest scoring weapon reaching `outcome` against `opponent`, if any does.
+    pub fn respond(&self, opponent: usize, outcome: RoundOutcome) -> Option<usize> {
+        (0..self.len())
+            .filter(|&e| self.outcome(e, opponent) == outcome)
+            .max_by_key(|&e| (self.scoring.weapon_points(e), std::cmp::Reverse(e)))
+    }
+
+    /// Weapon called `token` by name, or by letter counting from `first_code`.
+    fn weapon(&self, token: &str, first_code: u8) -> Option<usize> {
+        let code = |index: usize| {
+            char::from_u32(first_code as u32 + index as u32).filter(char::is_ascii_uppercase)
+        };
+
+        self.names
+            .iter()
+            .enumerate()
+            .find(|(index, name)| {
+                name.eq_ignore_ascii_case(token)
+                    || (token.len() == 1 && code(*index) == token.chars().next())
+            })
+            .map(|e| e.0)
+    }
+
+    /// Parses a guide into rounds of opponent and own weapon.
+    pub fn parse_guide(
+        &self,
+        input: &str,
+        reading: Reading,
+    ) -> Result<Vec<(usize, usize)>, ParseError> {
+        let response_code = (b'Z' as usize + 1)
+            .saturating_sub(self.len())
+            .max(b'A' as usize) as u8;
+
+        parse_lines(input, |line| {
+            let tokens = line.split_whitespace().collect::<Vec<&str>>();
+
+            let opponent_token = token(line, &tokens, 0, "opponent weapon")?;
+            let me_token = token(line, &tokens, 1, "response")?;
+
+            let opponent = self
+                .weapon(opponent_token, b'A')
+                .ok_or_else(|| ParseError::at(line, opponent_token, "Unknown weapon"))?;
+
+            let me = match reading {
+                Reading::Weapon => self
+                    .weapon(me_token, response_code)
+                    .ok_or_else(|| ParseError::at(line, me_token, "Unknown weapon"))?,
+                Reading::Outcome => {
+                    let outcome = RoundOutcome::try_from(me_token)
+                        .map_err(|e| ParseError::at(line, me_token, e))?;
+
+                    self.respond(opponent, outcome).ok_or_else(|| {
+                        ParseError::at(line, me_token, "No weapon reaches this outcome")
+                    })?
+                }
+            };
+
+            Ok((opponent, me))
+        })
+    }
+
+    pub fn points(&self, rounds: &[(usize, usize)]) -> i64 {
+        rounds
+            .iter()
+            .map(|&(opponent, me)| self.score(me, opponent) as i64)
+            .sum()
+    }
+}
+
+#[test]
+fn test_games() {
+    let game = Game::rock_paper_scissors();
+    let guide = "A Y\nB X\nC Z\n";
+
+    for (reading, points) in [(Reading::Weapon, 15), (Reading::Outcome, 12)] {
+        assert_eq!(
+            game.points(&game.parse_guide(guide, reading).unwrap()),
+            points
+        );
+    }
+
+    let game = Game::rock_paper_scissors_lizard_spock();
+    let (rock, paper, spock, lizard) = (0, 1, 3, 4);
+
+    assert_eq!(game.outcome(rock, lizard), RoundOutcome::Win);
+    assert_eq!(game.outcome(rock, spock), RoundOutcome::Lose);
+    assert_eq!(game.outcome(lizard, paper), RoundOutcome::Win);
+    // Both paper and spock beat rock; spock scores more.
+    assert_eq!(game.respond(rock, RoundOutcome::Win), Some(spock));
+
+    let rounds = game.parse_guide("A Z\nE spock\n", Reading::Weapon).unwrap();
+    assert_eq!(rounds, vec![(rock, lizard), (lizard, spock)]);
+    assert_eq!(game.points(&rounds), 5 + 4);
+
+    assert!(Game::cyclic(vec!["a".into(), "b".into()]).is_err());
+    assert!(Game::new(vec!["a".into(), "b".into()], vec![1, 2], &[]).is_err());
+}
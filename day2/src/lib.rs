@@ -0,0 +1,131 @@
+use common::{load, parse_lines, token, Answer, InputError, ParseError, Rng, Solution};
+
+mod cipher;
+mod game;
+mod report;
+mod scoring;
+mod strategy;
+mod tournament;
+pub use cipher::{
+    closest_to_score, closest_to_win_rate, decode, decodings, Decoding, DecodingTable, Meaning,
+};
+pub use game::{Game, Reading, DRAW_POINTS, LOSE_POINTS, WIN_POINTS};
+pub use report::{Report, RoundReport};
+pub use scoring::Scoring;
+pub use strategy::{AlwaysRock, FixedGuide, FrequencyCounter, Markov, Round, Strategy};
+pub use tournament::{play_match, tournament, Standing, Table};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Shape {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// Weapon index of the shape in `Game::rock_paper_scissors`.
+    pub fn index(self) -> usize {
+        self as usize - 1
+    }
+
+    pub fn get_shape(&self, expected_outcome: RoundOutcome) -> Shape {
+        let weapon = Game::classic().respond(self.index(), expected_outcome);
+
+        Shape::ALL[weapon.unwrap()]
+    }
+}
+
+impl TryFrom<&str> for Shape {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "A" | "X" => Ok(Shape::Rock),
+            "B" | "Y" => Ok(Shape::Paper),
+            "C" | "Z" => Ok(Shape::Scissors),
+            _ => Err("Unknown shape"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RoundOutcome {
+    Win,
+    Draw,
+    Lose,
+}
+
+impl TryFrom<&str> for RoundOutcome {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "X" => Ok(RoundOutcome::Lose),
+            "Y" => Ok(RoundOutcome::Draw),
+            "Z" => Ok(RoundOutcome::Win),
+            _ => Err("Unknown round result"),
+        }
+    }
+}
+
+/// Opponent shape with the response read as a shape and as an outcome, round by round.
+pub type Guide = Vec<(Shape, (Shape, Shape))>;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Guide;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let game = line.split(' ').collect::<Vec<&str>>();
+
+            let opponent_token = token(line, &game, 0, "opponent shape")?;
+            let me_token = token(line, &game, 1, "response")?;
+
+            let opponent = Shape::try_from(opponent_token)
+                .map_err(|e| ParseError::at(line, opponent_token, e))?;
+            let me = Shape::try_from(me_token).map_err(|e| ParseError::at(line, me_token, e))?;
+            let outcome =
+                RoundOutcome::try_from(me_token).map_err(|e| ParseError::at(line, me_token, e))?;
+
+            Ok((opponent, (me, opponent.get_shape(outcome))))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        get_original_points(input, &Scoring::default()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        get_new_points(input, &Scoring::default()).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.pick(&["A", "B", "C"]),
+                    rng.pick(&["X", "Y", "Z"])
+                )
+            })
+            .collect()
+    }
+}
+
+pub fn load_guide(path: &str) -> Result<Guide, InputError> {
+    load::<Day2>(path)
+}
+
+pub fn get_original_points(strategy: &[(Shape, (Shape, Shape))], scoring: &Scoring) -> i32 {
+    strategy.iter().map(|(a, b)| scoring.round(*a, b.0)).sum()
+}
+
+pub fn get_new_points(strategy: &[(Shape, (Shape, Shape))], scoring: &Scoring) -> i32 {
+    strategy.iter().map(|(a, b)| scoring.round(*a, b.1)).sum()
+}
@@ -1,115 +1,125 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
+use std::{env, process};
+
+use common::{default_input_path, Json, Solution};
+use day2::{
+    closest_to_score, closest_to_win_rate, decodings, get_new_points, get_original_points,
+    load_guide, tournament, AlwaysRock, Day2, DecodingTable, FixedGuide, FrequencyCounter, Markov,
+    Reading, Report, Scoring, Strategy, Table,
 };
 
-#[derive(Copy, Clone, PartialEq, Eq)]
-enum Shape {
-    Rock = 1,
-    Paper = 2,
-    Scissors = 3,
-}
+/// Rounds per match of `--tournament` without a count.
+const TOURNAMENT_ROUNDS: usize = 100;
 
-impl Shape {
-    fn get_shape(&self, expected_outcome: RoundOutcome) -> Shape {
-        match self {
-            Shape::Rock => match expected_outcome {
-                RoundOutcome::Win => Shape::Paper,
-                RoundOutcome::Draw => Shape::Rock,
-                RoundOutcome::Lose => Shape::Scissors,
-            },
-            Shape::Paper => match expected_outcome {
-                RoundOutcome::Win => Shape::Scissors,
-                RoundOutcome::Draw => Shape::Paper,
-                RoundOutcome::Lose => Shape::Rock,
-            },
-            Shape::Scissors => match expected_outcome {
-                RoundOutcome::Win => Shape::Rock,
-                RoundOutcome::Draw => Shape::Scissors,
-                RoundOutcome::Lose => Shape::Paper,
-            },
-        }
-    }
+/// What `--decode` looks for among the meanings of `X`, `Y` and `Z`.
+enum Target {
+    All,
+    Score(i64),
+    WinRate(f64),
 }
 
-impl TryFrom<&str> for Shape {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "A" | "X" => Ok(Shape::Rock),
-            "B" | "Y" => Ok(Shape::Paper),
-            "C" | "Z" => Ok(Shape::Scissors),
-            _ => Err("Unknown shape"),
+fn main() {
+    // `--report` prints the per-round breakdown of both readings, `--json` as JSON;
+    // `--scoring=FILE` reads scoring rules from FILE; `--decode` scores every meaning of
+    // `X`, `Y` and `Z`, or only the one closest to `--target-score=N` or `--win-rate=R`;
+    // `--tournament[=N]` plays every strategy against every other for N rounds (100 by
+    // default).
+    let (flags, args): (Vec<String>, Vec<String>) =
+        env::args().skip(1).partition(|e| e.starts_with("--"));
+
+    let mut report = None;
+    let mut scoring = Scoring::default();
+    let mut decode = None;
+    let mut rounds = None;
+
+    for flag in &flags {
+        if let Some(path) = flag.strip_prefix("--scoring=") {
+            scoring = Scoring::load(path).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                process::exit(1);
+            });
+        } else if let Some(x) = flag.strip_prefix("--target-score=") {
+            let x = x.parse::<i64>().unwrap_or_else(|_| {
+                eprintln!("Invalid target score: {}", x);
+                process::exit(2);
+            });
+            decode = Some(Target::Score(x));
+        } else if let Some(x) = flag.strip_prefix("--win-rate=") {
+            let rate = x.parse::<f64>().ok().filter(|e| (0.0..=1.0).contains(e));
+            decode = Some(Target::WinRate(rate.unwrap_or_else(|| {
+                eprintln!("Invalid win rate, expected 0 to 1: {}", x);
+                process::exit(2);
+            })));
+        } else if let Some(x) = flag.strip_prefix("--tournament=") {
+            rounds = Some(x.parse::<usize>().unwrap_or_else(|_| {
+                eprintln!("Invalid round count: {}", x);
+                process::exit(2);
+            }));
+        } else if flag == "--tournament" {
+            rounds = Some(TOURNAMENT_ROUNDS);
+        } else if flag == "--decode" {
+            decode.get_or_insert(Target::All);
+        } else if flag == "--report" || flag == "--json" {
+            report = Some(flag == "--json");
+        } else {
+            eprintln!("Unknown option: {}", flag);
+            process::exit(2);
         }
     }
-}
 
-#[derive(Copy, Clone)]
-enum RoundOutcome {
-    Win,
-    Draw,
-    Lose,
-}
+    let path = args
+        .first()
+        .cloned()
+        .unwrap_or_else(|| default_input_path(Day2::DAY));
+
+    let guide = load_guide(&path).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    if let Some(target) = decode {
+        let found = match target {
+            Target::All => decodings(&guide, &scoring),
+            Target::Score(x) => vec![closest_to_score(&guide, x, &scoring)],
+            Target::WinRate(x) => vec![closest_to_win_rate(&guide, x, &scoring)],
+        };
 
-impl TryFrom<&str> for RoundOutcome {
-    type Error = &'static str;
+        print!("{}", DecodingTable(&found));
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "X" => Ok(RoundOutcome::Lose),
-            "Y" => Ok(RoundOutcome::Draw),
-            "Z" => Ok(RoundOutcome::Win),
-            _ => Err("Unknown round result"),
-        }
+        return;
     }
-}
-
-fn load_strategy() -> Vec<(Shape, (Shape, Shape))> {
-    let mut out: Vec<(Shape, (Shape, Shape))> = Vec::new();
 
-    let file = File::open("./day2/input.txt").unwrap();
-    let reader = BufReader::new(file);
+    if let Some(json) = report {
+        let reports = [Reading::Weapon, Reading::Outcome].map(|e| Report::new(&guide, e, &scoring));
 
-    for line_result in reader.lines() {
-        if let Ok(line) = line_result {
-            let game = line.split(" ").collect::<Vec<&str>>();
-
-            let opponent = Shape::try_from(game[0]).unwrap();
-            let me = Shape::try_from(game[1]).unwrap();
-            let outcome = RoundOutcome::try_from(game[1]).unwrap();
-
-            out.push((opponent, (me, opponent.get_shape(outcome))));
+        if json {
+            let reports = Json::Array(reports.iter().map(Report::to_json).collect());
+            println!("{}", reports.pretty());
+        } else {
+            for report in &reports {
+                println!("{:?} reading\n{}", report.reading, report);
+            }
         }
-    }
 
-    out
-}
-
-fn main() {
-    let strategy = load_strategy();
-
-    let mut original_points = 0;
-    let mut new_points = 0;
-    for (a, b) in &strategy {
-        original_points += match (b.0 as i32) - (*a as i32) {
-            -2 => 6,
-            0 => 3,
-            1 => 6,
-            _ => 0,
-        };
-
-        new_points += match (b.1 as i32) - (*a as i32) {
-            -2 => 6,
-            0 => 3,
-            1 => 6,
-            _ => 0,
-        };
-
-        original_points += b.0 as i32;
-        new_points += b.1 as i32;
+        return;
     }
 
-    println!("First strategy score: {}", original_points);
-    println!("Second strategy score: {}", new_points);
+    println!(
+        "First strategy score: {}",
+        get_original_points(&guide, &scoring)
+    );
+    println!(
+        "Second strategy score: {}",
+        get_new_points(&guide, &scoring)
+    );
+
+    if let Some(rounds) = rounds {
+        let strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(FixedGuide::new(&guide)),
+            Box::new(AlwaysRock),
+            Box::new(FrequencyCounter),
+            Box::new(Markov),
+        ];
+
+        print!("{}", Table(&tournament(&strategies, rounds, &scoring)));
+    }
 }
@@ -0,0 +1,196 @@
+use std::fmt;
+
+use common::Json;
+
+use crate::{Guide, Reading, RoundOutcome, Scoring, Shape};
+
+/// Where the points of one round come from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RoundReport {
+    pub opponent: Shape,
+    pub me: Shape,
+    pub outcome: RoundOutcome,
+    pub shape_points: i32,
+    pub outcome_points: i32,
+}
+
+impl RoundReport {
+    pub fn points(&self) -> i32 {
+        self.shape_points + self.outcome_points
+    }
+}
+
+/// Round by round breakdown of a guide under one reading, with aggregates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub reading: Reading,
+    pub rounds: Vec<RoundReport>,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub longest_win_streak: usize,
+    pub longest_lose_streak: usize,
+}
+
+impl Report {
+    pub fn new(guide: &Guide, reading: Reading, scoring: &Scoring) -> Self {
+        let mut report = Report {
+            reading,
+            rounds: Vec::with_capacity(guide.len()),
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            longest_win_streak: 0,
+            longest_lose_streak: 0,
+        };
+
+        let (mut win_streak, mut lose_streak) = (0, 0);
+
+        for &(opponent, (weapon, outcome)) in guide {
+            let me = match reading {
+                Reading::Weapon => weapon,
+                Reading::Outcome => outcome,
+            };
+            let outcome = Scoring::outcome(opponent, me);
+
+            match outcome {
+                RoundOutcome::Win => {
+                    report.wins += 1;
+                    (win_streak, lose_streak) = (win_streak + 1, 0);
+                }
+                RoundOutcome::Draw => {
+                    report.draws += 1;
+                    (win_streak, lose_streak) = (0, 0);
+                }
+                RoundOutcome::Lose => {
+                    report.losses += 1;
+                    (win_streak, lose_streak) = (0, lose_streak + 1);
+                }
+            }
+
+            report.longest_win_streak = report.longest_win_streak.max(win_streak);
+            report.longest_lose_streak = report.longest_lose_streak.max(lose_streak);
+
+            report.rounds.push(RoundReport {
+                opponent,
+                me,
+                outcome,
+                shape_points: scoring.shape_points(me),
+                outcome_points: scoring.outcome_points(outcome),
+            });
+        }
+
+        report
+    }
+
+    pub fn total(&self) -> i64 {
+        self.rounds.iter().map(|e| e.points() as i64).sum()
+    }
+
+    pub fn to_json(&self) -> Json {
+        let name = |e: &dyn fmt::Debug| Json::from(format!("{:?}", e));
+
+        let rounds = self
+            .rounds
+            .iter()
+            .map(|e| {
+                Json::Object(vec![
+                    ("opponent".to_owned(), name(&e.opponent)),
+                    ("me".to_owned(), name(&e.me)),
+                    ("outcome".to_owned(), name(&e.outcome)),
+                    ("shape_points".to_owned(), (e.shape_points as i64).into()),
+                    (
+                        "outcome_points".to_owned(),
+                        (e.outcome_points as i64).into(),
+                    ),
+                ])
+            })
+            .collect();
+
+        Json::Object(vec![
+            ("reading".to_owned(), name(&self.reading)),
+            ("total".to_owned(), self.total().into()),
+            ("wins".to_owned(), (self.wins as i64).into()),
+            ("draws".to_owned(), (self.draws as i64).into()),
+            ("losses".to_owned(), (self.losses as i64).into()),
+            (
+                "longest_win_streak".to_owned(),
+                (self.longest_win_streak as i64).into(),
+            ),
+            (
+                "longest_lose_streak".to_owned(),
+                (self.longest_lose_streak as i64).into(),
+            ),
+            ("rounds".to_owned(), Json::Array(rounds)),
+        ])
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5}  {:<8}  {:<8}  {:<7}  {:>5}  {:>7}",
+            "Round", "Opponent", "Me", "Outcome", "Shape", "Outcome"
+        )?;
+
+        for (index, round) in self.rounds.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>5}  {:<8}  {:<8}  {:<7}  {:>5}  {:>7}",
+                index + 1,
+                format!("{:?}", round.opponent),
+                format!("{:?}", round.me),
+                format!("{:?}", round.outcome),
+                round.shape_points,
+                round.outcome_points
+            )?;
+        }
+
+        writeln!(
+            f,
+            "{} points: {} won, {} drawn, {} lost; longest streaks {} won, {} lost",
+            self.total(),
+            self.wins,
+            self.draws,
+            self.losses,
+            self.longest_win_streak,
+            self.longest_lose_streak
+        )
+    }
+}
+
+#[test]
+fn test_report() {
+    use common::Solution;
+
+    let guide = crate::Day2::parse("A Y\nB X\nC Z\nA Y\n").unwrap();
+
+    let report = Report::new(&guide, Reading::Weapon, &Scoring::default());
+    assert_eq!(report.total(), 15 + 8);
+    assert_eq!((report.wins, report.draws, report.losses), (2, 1, 1));
+    assert_eq!(
+        (report.longest_win_streak, report.longest_lose_streak),
+        (1, 1)
+    );
+
+    let report = Report::new(&guide, Reading::Outcome, &Scoring::default());
+    assert_eq!(report.total(), 12 + 4);
+    assert_eq!(report.rounds[2].outcome, RoundOutcome::Win);
+    assert_eq!(
+        (
+            report.rounds[2].shape_points,
+            report.rounds[2].outcome_points
+        ),
+        (1, 6)
+    );
+
+    let json = report.to_json();
+    assert_eq!(json.get("total").and_then(Json::as_i64), Some(16));
+    assert_eq!(
+        json.get("rounds")
+            .and_then(Json::as_array)
+            .map(<[Json]>::len),
+        Some(4)
+    );
+}
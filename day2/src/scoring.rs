@@ -0,0 +1,156 @@
+use common::{input_name, parse_lines, parse_token, read_input, InputError, ParseError};
+
+use crate::{Game, RoundOutcome, Shape, DRAW_POINTS, LOSE_POINTS, WIN_POINTS};
+
+/// Points for the weapon played and for the outcome of each round.
+///
+/// Defaults to the puzzle's rules; change single values with the `with_` methods or read
+/// them from a file of `key = points` lines, keys being weapon names (`rock`, `paper` and
+/// `scissors` in the puzzle), `win`, `draw` and `lose`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoring {
+    /// Points per weapon, by index in its `Game`.
+    pub weapons: Vec<i32>,
+    pub win: i32,
+    pub draw: i32,
+    pub lose: i32,
+}
+
+impl Default for Scoring {
+    fn default() -> Self {
+        Scoring::new(Shape::ALL.map(|e| e as i32).to_vec())
+    }
+}
+
+impl Scoring {
+    /// Scores `weapons`, with the puzzle's outcome points.
+    pub fn new(weapons: Vec<i32>) -> Self {
+        Scoring {
+            weapons,
+            win: WIN_POINTS,
+            draw: DRAW_POINTS,
+            lose: LOSE_POINTS,
+        }
+    }
+
+    pub fn with_weapon(mut self, weapon: usize, points: i32) -> Result<Self, String> {
+        if weapon >= self.weapons.len() {
+            return Err(format!("No weapon {} among {}", weapon, self.weapons.len()));
+        }
+
+        self.weapons[weapon] = points;
+        Ok(self)
+    }
+
+    /// Scores `shape` as in rock-paper-scissors, which needs points for three weapons.
+    pub fn with_shape(self, shape: Shape, points: i32) -> Result<Self, String> {
+        self.with_weapon(shape.index(), points)
+    }
+
+    pub fn with_outcome(mut self, outcome: RoundOutcome, points: i32) -> Self {
+        match outcome {
+            RoundOutcome::Win => self.win = points,
+            RoundOutcome::Draw => self.draw = points,
+            RoundOutcome::Lose => self.lose = points,
+        }
+        self
+    }
+
+    pub fn weapon_points(&self, weapon: usize) -> i32 {
+        self.weapons[weapon]
+    }
+
+    pub fn shape_points(&self, shape: Shape) -> i32 {
+        self.weapon_points(shape.index())
+    }
+
+    pub fn outcome_points(&self, outcome: RoundOutcome) -> i32 {
+        match outcome {
+            RoundOutcome::Win => self.win,
+            RoundOutcome::Draw => self.draw,
+            RoundOutcome::Lose => self.lose,
+        }
+    }
+
+    pub fn outcome(opponent: Shape, me: Shape) -> RoundOutcome {
+        Game::classic().outcome(me.index(), opponent.index())
+    }
+
+    pub fn round(&self, opponent: Shape, me: Shape) -> i32 {
+        self.shape_points(me) + self.outcome_points(Scoring::outcome(opponent, me))
+    }
+
+    /// Reads `key = points` lines over the scoring of rock-paper-scissors.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Scoring::parse_for(input, Game::classic())
+    }
+
+    /// Reads `key = points` lines over the scoring of `game`, skipping blank lines and `#`
+    /// comments.
+    pub fn parse_for(input: &str, game: &Game) -> Result<Self, ParseError> {
+        let mut scoring = game.scoring().clone();
+
+        parse_lines(input, |line| {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                return Ok(());
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(ParseError::missing(line, "Expected `key = points`"));
+            };
+            let (key, value) = (key.trim(), value.trim());
+            let points = parse_token::<i32>(line, value)?;
+
+            let weapon = (0..game.len()).find(|&e| game.name(e).eq_ignore_ascii_case(key));
+
+            match (weapon, key.to_ascii_lowercase().as_str()) {
+                (Some(x), _) => scoring.weapons[x] = points,
+                (None, "win") => scoring.win = points,
+                (None, "draw") => scoring.draw = points,
+                (None, "lose") => scoring.lose = points,
+                _ => return Err(ParseError::at(line, key, "Unknown scoring key")),
+            }
+
+            Ok(())
+        })?;
+
+        Ok(scoring)
+    }
+
+    pub fn load(path: &str) -> Result<Self, InputError> {
+        Scoring::load_for(path, Game::classic())
+    }
+
+    pub fn load_for(path: &str, game: &Game) -> Result<Self, InputError> {
+        let input = read_input(path).map_err(|e| InputError::Io(path.to_owned(), e))?;
+
+        Scoring::parse_for(&input, game).map_err(|e| InputError::Parse(e.in_file(input_name(path))))
+    }
+}
+
+#[test]
+fn test_scoring() {
+    let scoring = Scoring::parse("# league rules\nrock = 10\n\nWin=1\n").unwrap();
+    assert_eq!(
+        scoring,
+        Scoring::default()
+            .with_shape(Shape::Rock, 10)
+            .unwrap()
+            .with_outcome(RoundOutcome::Win, 1)
+    );
+    assert_eq!(scoring.round(Shape::Scissors, Shape::Rock), 11);
+    assert_eq!(scoring.round(Shape::Rock, Shape::Scissors), 3);
+
+    let error = Scoring::parse("rock = 1\nspock = 5\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+
+    let game = Game::rock_paper_scissors_lizard_spock();
+    let scoring = Scoring::parse_for("spock = 50\nwin = 100\n", &game).unwrap();
+    assert_eq!(scoring.weapons, vec![1, 2, 3, 50, 5]);
+
+    let game = game.with_scoring(scoring).unwrap();
+    let (rock, spock) = (0, 3);
+    assert_eq!(game.score(spock, rock), 150);
+    assert!(game.with_scoring(Scoring::default()).is_err());
+    assert!(Scoring::default().with_weapon(3, 1).is_err());
+}
@@ -0,0 +1,138 @@
+use crate::{Guide, RoundOutcome, Shape};
+
+/// One round as seen by a player.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub me: Shape,
+    pub opponent: Shape,
+}
+
+/// Picks the next shape to play from the rounds played so far in a match.
+pub trait Strategy {
+    fn name(&self) -> String;
+
+    fn play(&self, history: &[Round]) -> Shape;
+}
+
+fn counter(shape: Shape) -> Shape {
+    shape.get_shape(RoundOutcome::Win)
+}
+
+/// Most frequent of `shapes`, the earliest in `Shape::ALL` on ties.
+fn most_frequent(shapes: impl Iterator<Item = Shape>) -> Option<Shape> {
+    let mut counts = [0; 3];
+    for shape in shapes {
+        counts[shape.index()] += 1;
+    }
+
+    Shape::ALL
+        .into_iter()
+        .filter(|&e| counts[e.index()] > 0)
+        .rev()
+        .max_by_key(|&e| counts[e.index()])
+}
+
+/// Plays the shapes of a guide in order, starting over when they run out.
+pub struct FixedGuide {
+    pub shapes: Vec<Shape>,
+}
+
+impl FixedGuide {
+    /// Follows the responses of `guide` read as shapes, as in part 1.
+    pub fn new(guide: &Guide) -> Self {
+        FixedGuide {
+            shapes: guide.iter().map(|(_, e)| e.0).collect(),
+        }
+    }
+}
+
+impl Strategy for FixedGuide {
+    fn name(&self) -> String {
+        "Fixed guide".to_owned()
+    }
+
+    fn play(&self, history: &[Round]) -> Shape {
+        if self.shapes.is_empty() {
+            return Shape::Rock;
+        }
+
+        self.shapes[history.len() % self.shapes.len()]
+    }
+}
+
+pub struct AlwaysRock;
+
+impl Strategy for AlwaysRock {
+    fn name(&self) -> String {
+        "Always rock".to_owned()
+    }
+
+    fn play(&self, _: &[Round]) -> Shape {
+        Shape::Rock
+    }
+}
+
+/// Counters the shape the opponent has played most so far.
+pub struct FrequencyCounter;
+
+impl Strategy for FrequencyCounter {
+    fn name(&self) -> String {
+        "Frequency counter".to_owned()
+    }
+
+    fn play(&self, history: &[Round]) -> Shape {
+        most_frequent(history.iter().map(|e| e.opponent)).map_or(Shape::Rock, counter)
+    }
+}
+
+/// Counters the shape the opponent has most often followed their last shape with, falling
+/// back to `FrequencyCounter` until that has been seen.
+pub struct Markov;
+
+impl Strategy for Markov {
+    fn name(&self) -> String {
+        "Markov".to_owned()
+    }
+
+    fn play(&self, history: &[Round]) -> Shape {
+        let Some(last) = history.last() else {
+            return FrequencyCounter.play(history);
+        };
+
+        let next = history
+            .windows(2)
+            .filter(|e| e[0].opponent == last.opponent)
+            .map(|e| e[1].opponent);
+
+        match most_frequent(next) {
+            Some(x) => counter(x),
+            None => FrequencyCounter.play(history),
+        }
+    }
+}
+
+#[test]
+fn test_strategies() {
+    let history = |opponent: &[Shape]| {
+        opponent
+            .iter()
+            .map(|&e| Round {
+                me: Shape::Rock,
+                opponent: e,
+            })
+            .collect::<Vec<Round>>()
+    };
+
+    let guide = FixedGuide {
+        shapes: vec![Shape::Paper, Shape::Scissors],
+    };
+    assert_eq!(guide.play(&history(&[Shape::Rock])), Shape::Scissors);
+    assert_eq!(guide.play(&history(&[Shape::Rock; 2])), Shape::Paper);
+
+    let rounds = history(&[Shape::Rock, Shape::Paper, Shape::Paper, Shape::Rock]);
+    assert_eq!(AlwaysRock.play(&rounds), Shape::Rock);
+    assert_eq!(FrequencyCounter.play(&rounds), Shape::Paper);
+    // Rock was followed by paper once, so scissors beats the expected paper.
+    assert_eq!(Markov.play(&rounds), Shape::Scissors);
+    assert_eq!(Markov.play(&[]), Shape::Rock);
+}
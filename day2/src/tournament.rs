@@ -0,0 +1,157 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::{Round, Scoring, Strategy};
+
+/// Plays `rounds` rounds of `first` against `second`, returning both scores.
+pub fn play_match(
+    first: &dyn Strategy,
+    second: &dyn Strategy,
+    rounds: usize,
+    scoring: &Scoring,
+) -> (i64, i64) {
+    let mut first_history: Vec<Round> = Vec::with_capacity(rounds);
+    let mut second_history: Vec<Round> = Vec::with_capacity(rounds);
+    let mut scores = (0, 0);
+
+    for _ in 0..rounds {
+        let a = first.play(&first_history);
+        let b = second.play(&second_history);
+
+        scores.0 += scoring.round(b, a) as i64;
+        scores.1 += scoring.round(a, b) as i64;
+
+        first_history.push(Round { me: a, opponent: b });
+        second_history.push(Round { me: b, opponent: a });
+    }
+
+    scores
+}
+
+/// Results of one strategy over a tournament, counting whole matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub points: i64,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+/// Plays every pair of `strategies` once and ranks them by points, then by wins.
+pub fn tournament(
+    strategies: &[Box<dyn Strategy>],
+    rounds: usize,
+    scoring: &Scoring,
+) -> Vec<Standing> {
+    let mut standings = strategies
+        .iter()
+        .map(|e| Standing {
+            name: e.name(),
+            points: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        })
+        .collect::<Vec<Standing>>();
+
+    for a in 0..strategies.len() {
+        for b in a + 1..strategies.len() {
+            let (first, second) = play_match(
+                strategies[a].as_ref(),
+                strategies[b].as_ref(),
+                rounds,
+                scoring,
+            );
+
+            standings[a].points += first;
+            standings[b].points += second;
+
+            match first.cmp(&second) {
+                Ordering::Greater => {
+                    standings[a].wins += 1;
+                    standings[b].losses += 1;
+                }
+                Ordering::Less => {
+                    standings[a].losses += 1;
+                    standings[b].wins += 1;
+                }
+                Ordering::Equal => {
+                    standings[a].draws += 1;
+                    standings[b].draws += 1;
+                }
+            }
+        }
+    }
+
+    standings.sort_by_key(|e| (-e.points, -(e.wins as i64)));
+
+    standings
+}
+
+/// Ranked table of standings, best first.
+pub struct Table<'a>(pub &'a [Standing]);
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .0
+            .iter()
+            .map(|e| e.name.len())
+            .max()
+            .unwrap_or(0)
+            .max(8);
+
+        writeln!(
+            f,
+            "{:>2}  {:<width$}  {:>8}  {:>3}  {:>3}  {:>3}",
+            "#", "Strategy", "Points", "W", "D", "L"
+        )?;
+
+        for (rank, standing) in self.0.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>2}  {:<width$}  {:>8}  {:>3}  {:>3}  {:>3}",
+                rank + 1,
+                standing.name,
+                standing.points,
+                standing.wins,
+                standing.draws,
+                standing.losses
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_tournament() {
+    use crate::{AlwaysRock, FrequencyCounter, Markov};
+
+    assert_eq!(
+        play_match(&AlwaysRock, &AlwaysRock, 10, &Scoring::default()),
+        (40, 40)
+    );
+    // Paper from the second round on: a draw, then nine wins.
+    assert_eq!(
+        play_match(&FrequencyCounter, &AlwaysRock, 10, &Scoring::default()),
+        (4 + 9 * 8, 4 + 9)
+    );
+
+    let strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(AlwaysRock),
+        Box::new(FrequencyCounter),
+        Box::new(Markov),
+    ];
+    let standings = tournament(&strategies, 100, &Scoring::default());
+
+    assert_eq!(standings.last().unwrap().name, "Always rock");
+    assert_eq!(
+        standings
+            .iter()
+            .map(|e| e.wins + e.draws + e.losses)
+            .sum::<usize>(),
+        6
+    );
+    assert!(Table(&standings).to_string().starts_with(" #  Strategy"));
+}
@@ -0,0 +1,169 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, OnceLock},
+};
+
+use common::{input_name, parse_lines, parse_token, read_input, token, InputError, ParseError};
+
+use crate::ItemSet;
+
+/// Item types that may be packed, each with its priority.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    items: Vec<char>,
+    priorities: Vec<i32>,
+    indices: HashMap<char, usize>,
+}
+
+impl Alphabet {
+    pub fn new(entries: &[(char, i32)]) -> Result<Self, String> {
+        if entries.len() > ItemSet::CAPACITY {
+            return Err(format!(
+                "An alphabet holds at most {} items, not {}",
+                ItemSet::CAPACITY,
+                entries.len()
+            ));
+        }
+
+        for (index, (item, _)) in entries.iter().enumerate() {
+            if entries[..index].iter().any(|e| e.0 == *item) {
+                return Err(format!("Item {} is listed twice", item));
+            }
+        }
+
+        Ok(Alphabet {
+            items: entries.iter().map(|e| e.0).collect(),
+            priorities: entries.iter().map(|e| e.1).collect(),
+            indices: entries.iter().enumerate().map(|(i, e)| (e.0, i)).collect(),
+        })
+    }
+
+    /// The puzzle's alphabet: `a` to `z` with priorities 1 to 26, then `A` to `Z` with 27 to 52.
+    pub fn standard() -> Arc<Alphabet> {
+        static STANDARD: OnceLock<Arc<Alphabet>> = OnceLock::new();
+
+        STANDARD
+            .get_or_init(|| {
+                let entries = ('a'..='z')
+                    .chain('A'..='Z')
+                    .zip(1..)
+                    .collect::<Vec<(char, i32)>>();
+
+                Arc::new(Alphabet::new(&entries).unwrap())
+            })
+            .clone()
+    }
+
+    /// Reads lines of an item character and its priority, separated by whitespace.
+    ///
+    /// Blank lines are skipped; there are no comments, as `#` may well be an item.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut seen: HashSet<char> = HashSet::new();
+
+        let entries = parse_lines(input, |line| {
+            let tokens = line.split_whitespace().collect::<Vec<&str>>();
+            if tokens.is_empty() {
+                return Ok(None);
+            }
+
+            let item = tokens[0];
+            let mut chars = item.chars();
+            let (Some(x), None) = (chars.next(), chars.next()) else {
+                return Err(ParseError::at(line, item, "Expected a single character"));
+            };
+
+            let priority = token(line, &tokens, 1, "priority")?;
+            let priority = parse_token::<i32>(line, priority)?;
+
+            if let Some(extra) = tokens.get(2) {
+                return Err(ParseError::at(line, extra, "Unexpected token"));
+            }
+
+            if !seen.insert(x) {
+                return Err(ParseError::at(line, item, "Item is listed twice"));
+            }
+            if seen.len() > ItemSet::CAPACITY {
+                return Err(ParseError::at(
+                    line,
+                    item,
+                    format!("An alphabet holds at most {} items", ItemSet::CAPACITY),
+                ));
+            }
+
+            Ok(Some((x, priority)))
+        })?;
+
+        let entries = entries.into_iter().flatten().collect::<Vec<(char, i32)>>();
+
+        // Repeated items and the capacity are checked line by line above.
+        Ok(Alphabet::new(&entries).unwrap())
+    }
+
+    pub fn load(path: &str) -> Result<Self, InputError> {
+        let input = read_input(path).map_err(|e| InputError::Io(path.to_owned(), e))?;
+
+        Alphabet::parse(&input).map_err(|e| InputError::Parse(e.in_file(input_name(path))))
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn index(&self, item: char) -> Option<usize> {
+        self.indices.get(&item).copied()
+    }
+
+    pub fn item(&self, index: usize) -> char {
+        self.items[index]
+    }
+
+    pub fn priority(&self, item: char) -> Option<i32> {
+        self.index(item).map(|e| self.priorities[e])
+    }
+
+    /// Items of `set`, in alphabet order.
+    pub fn items(&self, set: ItemSet) -> Vec<char> {
+        set.indices().map(|e| self.items[e]).collect()
+    }
+
+    pub fn priority_sum(&self, set: ItemSet) -> i32 {
+        set.indices().map(|e| self.priorities[e]).sum()
+    }
+}
+
+#[test]
+fn test_alphabet() {
+    let alphabet = Alphabet::parse("0 10\n\n# 20\n\u{e9} 30\n").unwrap();
+
+    assert_eq!(alphabet.len(), 3);
+    assert_eq!(alphabet.priority('#'), Some(20));
+    assert_eq!(alphabet.priority('a'), None);
+
+    let set = "\u{e9}0"
+        .chars()
+        .filter_map(|e| alphabet.index(e))
+        .collect();
+    assert_eq!(alphabet.items(set), vec!['0', '\u{e9}']);
+    assert_eq!(alphabet.priority_sum(set), 40);
+
+    let error = Alphabet::parse("0 1\nab 2\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.message.as_str()),
+        (2, "Expected a single character")
+    );
+    let error = Alphabet::parse("0 1\n\n 0 2\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.message.as_str()),
+        (3, 2, "Item is listed twice")
+    );
+
+    // Compartments split by characters, not bytes.
+    let rucksack = crate::Rucksack::parse("\u{e9}0\u{e9}#", &Arc::new(alphabet)).unwrap();
+    assert_eq!(rucksack.get_shared_items(), vec!['\u{e9}']);
+
+    assert_eq!(Alphabet::standard().priority('Z'), Some(52));
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+
+use crate::Rucksack;
+
+/// Rucksacks per group in the puzzle.
+pub const GROUP_SIZE: usize = 3;
+
+/// Item types common to every rucksack of a group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Badge {
+    Unique(char),
+    /// No item type is carried by the whole group.
+    Missing,
+    Several(Vec<char>),
+    /// Trailing group holding only this many rucksacks.
+    Incomplete(usize),
+}
+
+impl Badge {
+    pub fn item(&self) -> Option<char> {
+        match self {
+            Badge::Unique(x) => Some(*x),
+            _ => None,
+        }
+    }
+}
+
+/// Badge of a complete group; an empty group has none.
+pub fn get_badge(group: &[Rucksack]) -> Badge {
+    let Some(first) = group.first() else {
+        return Badge::Missing;
+    };
+
+    let common = group[1..]
+        .iter()
+        .fold(first.items(), |common, e| common & e.items());
+
+    let items = first.alphabet().items(common);
+
+    match items[..] {
+        [] => Badge::Missing,
+        [x] => Badge::Unique(x),
+        _ => Badge::Several(items),
+    }
+}
+
+/// Badge of each consecutive group of `size` rucksacks.
+pub fn group_badges(rucksacks: &[Rucksack], size: usize) -> Vec<Badge> {
+    rucksacks
+        .chunks(size.max(1))
+        .map(|e| {
+            if e.len() < size {
+                Badge::Incomplete(e.len())
+            } else {
+                get_badge(e)
+            }
+        })
+        .collect()
+}
+
+/// Sum of the badge priorities of groups of `size`, skipping groups without a unique badge.
+pub fn get_group_badge_priority_sum(rucksacks: &[Rucksack], size: usize) -> i32 {
+    rucksacks
+        .chunks(size.max(1))
+        .filter(|e| e.len() == size)
+        .filter_map(|e| e[0].alphabet().priority(get_badge(e).item()?))
+        .sum()
+}
+
+/// Group without a unique badge, spanning input lines `first_line` to `last_line`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadgeIssue {
+    pub first_line: usize,
+    pub last_line: usize,
+    pub badge: Badge,
+}
+
+pub fn badge_issues(rucksacks: &[Rucksack], size: usize) -> Vec<BadgeIssue> {
+    let size = size.max(1);
+
+    group_badges(rucksacks, size)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, badge)| badge.item().is_none())
+        .map(|(index, badge)| BadgeIssue {
+            first_line: index * size + 1,
+            last_line: (index * size + size).min(rucksacks.len()),
+            badge,
+        })
+        .collect()
+}
+
+/// Lists badge issues, one per line.
+pub struct BadgeReport<'a>(pub &'a [BadgeIssue]);
+
+impl fmt::Display for BadgeReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in self.0 {
+            if issue.first_line == issue.last_line {
+                write!(f, "Line {}: ", issue.first_line)?;
+            } else {
+                write!(f, "Lines {}-{}: ", issue.first_line, issue.last_line)?;
+            }
+
+            match &issue.badge {
+                Badge::Unique(x) => writeln!(f, "badge {}", x)?,
+                Badge::Missing => writeln!(f, "no common item")?,
+                Badge::Several(x) => writeln!(
+                    f,
+                    "several common items: {}",
+                    x.iter()
+                        .map(|e| e.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )?,
+                Badge::Incomplete(x) => writeln!(
+                    f,
+                    "incomplete group of {} rucksack{}",
+                    x,
+                    if *x == 1 { "" } else { "s" }
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_badges() {
+    let rucksacks = ["abcX", "bdeX", "bfgX", "hiaa", "jkbb", "ab", "ab"]
+        .into_iter()
+        .map(Rucksack::from)
+        .collect::<Vec<Rucksack>>();
+
+    assert_eq!(
+        group_badges(&rucksacks, 3),
+        vec![
+            Badge::Several(vec!['b', 'X']),
+            Badge::Missing,
+            Badge::Incomplete(1)
+        ]
+    );
+    assert_eq!(
+        group_badges(&rucksacks[4..], 2),
+        vec![Badge::Unique('b'), Badge::Incomplete(1)]
+    );
+    assert_eq!(get_group_badge_priority_sum(&rucksacks[4..], 2), 2);
+    assert_eq!(get_group_badge_priority_sum(&rucksacks[5..], 2), 0);
+
+    assert_eq!(
+        BadgeReport(&badge_issues(&rucksacks, 3)).to_string(),
+        "Lines 1-3: several common items: b, X\n\
+         Lines 4-6: no common item\n\
+         Line 7: incomplete group of 1 rucksack\n"
+    );
+}
@@ -0,0 +1,84 @@
+use std::ops::{BitAnd, BitOr};
+
+/// Set of item types, one bit per index in an `Alphabet`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ItemSet(u128);
+
+impl ItemSet {
+    /// Item types a set can hold.
+    pub const CAPACITY: usize = 128;
+
+    pub fn new() -> Self {
+        ItemSet(0)
+    }
+
+    /// Adds the item type at `index`, returning whether it was not in the set yet.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let added = !self.contains(index);
+        self.0 |= 1 << index;
+
+        added
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.0 & 1 << index != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Indices of the item types in the set, in increasing order.
+    pub fn indices(&self) -> impl Iterator<Item = usize> {
+        let bits = self.0;
+
+        (0..ItemSet::CAPACITY).filter(move |&e| bits & 1 << e != 0)
+    }
+}
+
+impl FromIterator<usize> for ItemSet {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut set = ItemSet::new();
+        for index in iter {
+            set.insert(index);
+        }
+
+        set
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        ItemSet(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        ItemSet(self.0 | rhs.0)
+    }
+}
+
+#[test]
+fn test_item_set() {
+    let a = [1, 5, 127, 5].into_iter().collect::<ItemSet>();
+    let b = [5, 64].into_iter().collect::<ItemSet>();
+
+    assert_eq!(a.len(), 3);
+    assert_eq!((a & b).indices().collect::<Vec<usize>>(), vec![5]);
+    assert_eq!(
+        (a | b).indices().collect::<Vec<usize>>(),
+        vec![1, 5, 64, 127]
+    );
+
+    let mut set = ItemSet::new();
+    assert!(set.insert(127) && !set.insert(127) && set.contains(127) && !set.contains(0));
+}
@@ -0,0 +1,256 @@
+use std::sync::Arc;
+
+use common::{input_name, parse_lines, read_input, Answer, InputError, ParseError, Rng, Solution};
+
+mod alphabet;
+mod badges;
+mod items;
+mod planner;
+mod validation;
+pub use alphabet::Alphabet;
+pub use badges::{
+    badge_issues, get_badge, get_group_badge_priority_sum, group_badges, Badge, BadgeIssue,
+    BadgeReport, GROUP_SIZE,
+};
+pub use items::ItemSet;
+pub use planner::{plan, plan_rucksack, Move, Plan};
+pub use validation::{validate, Anomaly, Finding, Validation, MISPLACED_SHOWN};
+
+pub struct Rucksack {
+    first_compartment: ItemSet,
+    second_compartment: ItemSet,
+    /// Alphabet indices of every item, the first compartment taking the first `half`.
+    contents: Vec<usize>,
+    half: usize,
+    /// Characters outside the alphabet, set aside by `parse_lenient`.
+    invalid: Vec<char>,
+    alphabet: Arc<Alphabet>,
+}
+
+impl Rucksack {
+    /// Splits `line` in half by characters, rejecting items missing from `alphabet`.
+    pub fn parse(line: &str, alphabet: &Arc<Alphabet>) -> Result<Self, ParseError> {
+        if let Some((offset, item)) = line.char_indices().find(|e| alphabet.index(e.1).is_none()) {
+            let token = &line[offset..offset + item.len_utf8()];
+            return Err(ParseError::at(line, token, "Unknown item"));
+        }
+
+        Ok(Rucksack::parse_lenient(line, alphabet))
+    }
+
+    /// Like `parse`, but sets items missing from `alphabet` aside instead of rejecting them;
+    /// the rest is split in half.
+    pub fn parse_lenient(line: &str, alphabet: &Arc<Alphabet>) -> Self {
+        let mut indices: Vec<usize> = Vec::new();
+        let mut invalid: Vec<char> = Vec::new();
+
+        for item in line.chars() {
+            match alphabet.index(item) {
+                Some(x) => indices.push(x),
+                None => invalid.push(item),
+            }
+        }
+
+        let half = indices.len() / 2;
+        let (first, second) = indices.split_at(half);
+
+        Rucksack {
+            first_compartment: first.iter().copied().collect(),
+            second_compartment: second.iter().copied().collect(),
+            contents: indices,
+            half,
+            invalid,
+            alphabet: alphabet.clone(),
+        }
+    }
+
+    pub fn alphabet(&self) -> &Alphabet {
+        &self.alphabet
+    }
+
+    pub fn invalid_items(&self) -> &[char] {
+        &self.invalid
+    }
+
+    /// Number of items packed.
+    pub fn len(&self) -> usize {
+        self.contents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+
+    /// Items of each compartment, in packing order.
+    pub fn compartment_items(&self) -> (Vec<char>, Vec<char>) {
+        let (first, second) = self.contents.split_at(self.half);
+        let items = |e: &[usize]| e.iter().map(|&e| self.alphabet.item(e)).collect();
+
+        (items(first), items(second))
+    }
+
+    pub fn compartments(&self) -> (ItemSet, ItemSet) {
+        (self.first_compartment, self.second_compartment)
+    }
+
+    /// Item types in either compartment.
+    pub fn items(&self) -> ItemSet {
+        self.first_compartment | self.second_compartment
+    }
+
+    /// Item types in both compartments.
+    pub fn shared(&self) -> ItemSet {
+        self.first_compartment & self.second_compartment
+    }
+
+    pub fn get_shared_items(&self) -> Vec<char> {
+        self.alphabet.items(self.shared())
+    }
+
+    pub fn get_shared_priority(&self) -> i32 {
+        self.alphabet.priority_sum(self.shared())
+    }
+}
+
+/// Rucksack over the standard alphabet, setting anything else aside as `parse_lenient` does.
+impl From<&str> for Rucksack {
+    fn from(value: &str) -> Self {
+        Rucksack::parse_lenient(value, &Alphabet::standard())
+    }
+}
+
+/// Priority of `item` in the standard alphabet, or 0 outside of it.
+pub fn get_priority(item: char) -> i32 {
+    Alphabet::standard().priority(item).unwrap_or(0)
+}
+
+pub fn get_priority_sum(rucksacks: &[Rucksack]) -> i32 {
+    rucksacks.iter().map(Rucksack::get_shared_priority).sum()
+}
+
+pub fn get_badge_priority_sum(rucksacks: &[Rucksack]) -> i32 {
+    get_group_badge_priority_sum(rucksacks, GROUP_SIZE)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_rucksacks(input, &Alphabet::standard())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        get_priority_sum(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        get_badge_priority_sum(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let items = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+
+        let mut out = String::new();
+
+        for _ in 0..size.max(1).div_ceil(3) {
+            let mut pool = items.clone();
+            rng.shuffle(&mut pool);
+
+            // Every elf of the group packs from its own 17 item types, so the badge is the
+            // only type all three carry.
+            let badge = pool[0];
+            for own in pool[1..].chunks(17) {
+                out.push_str(&generate_rucksack(rng, badge, own));
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+}
+
+pub fn parse_rucksacks(input: &str, alphabet: &Arc<Alphabet>) -> Result<Vec<Rucksack>, ParseError> {
+    parse_lines(input, |line| Rucksack::parse(line, alphabet))
+}
+
+/// Parses every line of `input` with `Rucksack::parse_lenient`, for validation.
+pub fn parse_rucksacks_lenient(input: &str, alphabet: &Arc<Alphabet>) -> Vec<Rucksack> {
+    input
+        .lines()
+        .map(|e| Rucksack::parse_lenient(e, alphabet))
+        .collect()
+}
+
+/// Loads rucksacks from `path`, over the standard alphabet unless one is given.
+pub fn load_rucksacks(
+    path: &str,
+    alphabet: Option<&Arc<Alphabet>>,
+) -> Result<Vec<Rucksack>, InputError> {
+    let input = read_input(path).map_err(|e| InputError::Io(path.to_owned(), e))?;
+
+    parse_rucksacks(&input, alphabet.unwrap_or(&Alphabet::standard()))
+        .map_err(|e| InputError::Parse(e.in_file(input_name(path))))
+}
+
+/// Rucksack holding `badge` once and `own[0]` in both compartments, filled from the rest of
+/// `own` without any other type ending up in both.
+fn generate_rucksack(rng: &mut Rng, badge: char, own: &[char]) -> String {
+    let shared = own[0];
+    let (first_only, second_only) = own[1..].split_at(own.len() / 2);
+
+    let mut first = vec![shared];
+    let mut second = vec![shared];
+
+    if rng.chance(50) {
+        first.push(badge);
+    } else {
+        second.push(badge);
+    }
+
+    let half = rng.range(2..=16) as usize;
+    while first.len() < half {
+        first.push(*rng.pick(first_only));
+    }
+    while second.len() < half {
+        second.push(*rng.pick(second_only));
+    }
+
+    rng.shuffle(&mut first);
+    rng.shuffle(&mut second);
+
+    first.into_iter().chain(second).collect()
+}
+
+#[test]
+fn test_example() {
+    let data = vec![
+        "vJrwpWtwJgWrhcsFMMfFFhFp",
+        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+        "PmmdzqPrVvPwwTWBwg",
+        "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+        "ttgJtRGJQctTZtZT",
+        "CrZsJsPPZsGzwwsLwLmpwMDw",
+    ];
+
+    let mut rucksacks: Vec<Rucksack> = Vec::new();
+
+    for rucksack_data in &data {
+        rucksacks.push(Rucksack::from(*rucksack_data));
+    }
+
+    assert_eq!(
+        get_priority_sum(&rucksacks),
+        157,
+        "get_priority_sum wrong sum"
+    );
+
+    assert_eq!(
+        get_badge_priority_sum(&rucksacks),
+        70,
+        "get_badges_priority_sum wrong sum"
+    );
+}
@@ -1,151 +1,102 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-struct Rucksack {
-    first_compartment: Vec<char>,
-    second_compartment: Vec<char>,
-}
-
-impl Rucksack {
-    fn get_shared_items(&self) -> Vec<char> {
-        let mut shared_items: Vec<char> = Vec::new();
-
-        for item in &self.first_compartment {
-            if self.second_compartment.contains(&item) && !shared_items.contains(&item) {
-                shared_items.push(*item);
-            }
-        }
-
-        shared_items
-    }
-}
-
-impl From<&str> for Rucksack {
-    fn from(value: &str) -> Self {
-        let len = value.len();
-
-        let chars = value.chars().collect::<Vec<char>>();
-
-        let chunks = chars.chunks(len / 2).collect::<Vec<_>>();
+use std::{env, process, sync::Arc};
 
-        Rucksack {
-            first_compartment: chunks[0].to_vec(),
-            second_compartment: chunks[1].to_vec(),
-        }
-    }
-}
-
-fn get_badge(a: &Rucksack, b: &Rucksack, c: &Rucksack) -> char {
-    for item in a
-        .first_compartment
-        .iter()
-        .chain(a.second_compartment.iter())
-    {
-        if let Some(_) = b
-            .first_compartment
-            .iter()
-            .chain(b.second_compartment.iter())
-            .find(|&e| e == item)
-        {
-            if let Some(_) = c
-                .first_compartment
-                .iter()
-                .chain(c.second_compartment.iter())
-                .find(|&e| e == item)
-            {
-                return *item;
-            }
-        }
-    }
-
-    ' '
-}
-
-fn get_priority(item: char) -> i32 {
-    match item {
-        'a'..='z' => item as i32 - 96,
-        'A'..='Z' => item as i32 - 38,
-        _ => 0,
-    }
-}
-
-fn get_priority_sum(rucksacks: &Vec<Rucksack>) -> i32 {
-    let mut sum = 0;
-
-    for rucksack in rucksacks {
-        let shared_items = rucksack.get_shared_items();
+use common::{default_input_path, read_input, InputError, Solution};
+use day3::{
+    badge_issues, get_badge_priority_sum, get_group_badge_priority_sum, get_priority_sum,
+    load_rucksacks, parse_rucksacks_lenient, plan, validate, Alphabet, BadgeReport, Day3,
+    GROUP_SIZE,
+};
 
-        for shared_item in shared_items {
-            sum += get_priority(shared_item);
+fn main() {
+    // `--alphabet=FILE` reads the item alphabet and priorities from FILE; `--plan` prints
+    // moves separating every rucksack, with `--capacity=N` items per compartment;
+    // `--validate` reports anomalies, setting invalid items aside instead of failing.
+    let (flags, args): (Vec<String>, Vec<String>) =
+        env::args().skip(1).partition(|e| e.starts_with("--"));
+
+    let mut alphabet = None;
+    let mut reorganize = false;
+    let mut capacity = None;
+    let mut check = false;
+
+    for flag in &flags {
+        if let Some(path) = flag.strip_prefix("--alphabet=") {
+            let loaded = Alphabet::load(path).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                process::exit(1);
+            });
+            alphabet = Some(Arc::new(loaded));
+        } else if let Some(x) = flag.strip_prefix("--capacity=") {
+            let x = x.parse::<usize>().unwrap_or_else(|_| {
+                eprintln!("Invalid capacity: {}", x);
+                process::exit(2);
+            });
+            (reorganize, capacity) = (true, Some(x));
+        } else if flag == "--plan" {
+            reorganize = true;
+        } else if flag == "--validate" {
+            check = true;
+        } else {
+            eprintln!("Unknown option: {}", flag);
+            process::exit(2);
         }
     }
 
-    sum
-}
+    let path = args
+        .first()
+        .cloned()
+        .unwrap_or_else(|| default_input_path(Day3::DAY));
+
+    let group_size = args.get(1).map_or(GROUP_SIZE, |e| {
+        e.parse::<usize>()
+            .ok()
+            .filter(|&e| e > 0)
+            .unwrap_or_else(|| {
+                eprintln!("Invalid group size: {}", e);
+                process::exit(2);
+            })
+    });
+
+    let rucksacks = if check {
+        let input = read_input(&path).unwrap_or_else(|e| {
+            eprintln!("{}", InputError::Io(path.clone(), e));
+            process::exit(1);
+        });
+
+        let rucksacks =
+            parse_rucksacks_lenient(&input, &alphabet.unwrap_or_else(Alphabet::standard));
+        print!("{}", validate(&rucksacks));
+
+        rucksacks
+    } else {
+        load_rucksacks(&path, alphabet.as_ref()).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        })
+    };
 
-fn get_badge_priority_sum(rucksacks: &Vec<Rucksack>) -> i32 {
-    let mut sum = 0;
+    println!("Sum of priorities: {}", get_priority_sum(&rucksacks));
 
-    for chunk in rucksacks.chunks(3) {
-        sum += get_priority(get_badge(&chunk[0], &chunk[1], &chunk[2]));
+    if group_size == GROUP_SIZE {
+        println!(
+            "Sum of badge priorities: {}",
+            get_badge_priority_sum(&rucksacks)
+        );
+    } else {
+        println!(
+            "Sum of badge priorities in groups of {}: {}",
+            group_size,
+            get_group_badge_priority_sum(&rucksacks, group_size)
+        );
     }
 
-    sum
-}
-
-fn load_rucksacks(path: &'static str) -> Vec<Rucksack> {
-    let mut rucksacks: Vec<Rucksack> = Vec::new();
-
-    let file = File::open(path).unwrap();
-    let reader = BufReader::new(file);
-
-    for line_result in reader.lines() {
-        if let Ok(line) = line_result {
-            rucksacks.push(Rucksack::from(line.as_str()));
-        }
+    let issues = badge_issues(&rucksacks, group_size);
+    if !issues.is_empty() {
+        println!("{} groups without a unique badge:", issues.len());
+        print!("{}", BadgeReport(&issues));
     }
 
-    rucksacks
-}
-
-fn main() {
-    let rucksacks = load_rucksacks("./day3/input.txt");
-
-    println!("Sum of priorities: {}", get_priority_sum(&rucksacks));
-    println!(
-        "Sum of badge priorities: {}",
-        get_badge_priority_sum(&rucksacks)
-    );
-}
-
-#[test]
-fn test_example() {
-    let data = vec![
-        "vJrwpWtwJgWrhcsFMMfFFhFp",
-        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
-        "PmmdzqPrVvPwwTWBwg",
-        "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
-        "ttgJtRGJQctTZtZT",
-        "CrZsJsPPZsGzwwsLwLmpwMDw",
-    ];
-
-    let mut rucksacks: Vec<Rucksack> = Vec::new();
-
-    for rucksack_data in &data {
-        rucksacks.push(Rucksack::from(*rucksack_data));
+    if reorganize {
+        print!("{}", plan(&rucksacks, capacity));
     }
-
-    assert_eq!(
-        get_priority_sum(&rucksacks),
-        157,
-        "get_priority_sum wrong sum"
-    );
-
-    assert_eq!(
-        get_badge_priority_sum(&rucksacks),
-        70,
-        "get_badges_priority_sum wrong sum"
-    );
 }
@@ -0,0 +1,206 @@
+use std::fmt;
+
+use crate::{ItemSet, Rucksack};
+
+/// One step of a reorganization, naming rucksacks by input line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Move {
+    /// Exchanges an item of the first compartment with one of the second.
+    Swap {
+        rucksack: usize,
+        first: char,
+        second: char,
+    },
+    ToFirst {
+        rucksack: usize,
+        item: char,
+    },
+    ToSecond {
+        rucksack: usize,
+        item: char,
+    },
+}
+
+/// Moves leaving no item type in both compartments of any rucksack, and the rucksacks that
+/// cannot get there within capacity.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Plan {
+    pub moves: Vec<Move>,
+    pub infeasible: Vec<usize>,
+}
+
+/// Fewest moves and swaps separating the compartments of `rucksack`, or `None` when no
+/// split fits.
+///
+/// Each compartment holds up to `capacity` items; without one, both keep their current
+/// size and only swaps are possible. Items never leave their rucksack.
+pub fn plan_rucksack(
+    rucksack: &Rucksack,
+    number: usize,
+    capacity: Option<usize>,
+) -> Option<Vec<Move>> {
+    let (first, second) = rucksack.contents.split_at(rucksack.half);
+    let n = rucksack.contents.len();
+
+    let (min_first, max_first) = match capacity {
+        Some(x) => (n.saturating_sub(x), x.min(n)),
+        None => (first.len(), first.len()),
+    };
+
+    let mut counts = vec![(0, 0); ItemSet::CAPACITY];
+    for &item in first {
+        counts[item].0 += 1;
+    }
+    for &item in second {
+        counts[item].1 += 1;
+    }
+
+    // Choosing the types kept in the first compartment fixes its size `s`. With `p` of
+    // them already there, `first.len() - p` items must leave it and `s - p` come in, so
+    // for every size the best choice keeps the most items in place.
+    let types = rucksack.items().indices().collect::<Vec<usize>>();
+    let mut best: Vec<Option<usize>> = vec![None; n + 1];
+    best[0] = Some(0);
+    let mut taken: Vec<Vec<bool>> = Vec::with_capacity(types.len());
+
+    for &item in &types {
+        let (a, b) = counts[item];
+        let mut next = best.clone();
+        let mut take = vec![false; n + 1];
+
+        for size in a + b..=n {
+            if let Some(p) = best[size - a - b] {
+                if next[size].is_none_or(|e| p + a > e) {
+                    next[size] = Some(p + a);
+                    take[size] = true;
+                }
+            }
+        }
+
+        best = next;
+        taken.push(take);
+    }
+
+    let (_, mut size) = (min_first..=max_first)
+        .filter_map(|s| best[s].map(|p| ((first.len() - p).max(s - p), s)))
+        .min()?;
+
+    let mut kept_first = ItemSet::new();
+    for (index, &item) in types.iter().enumerate().rev() {
+        if taken[index][size] {
+            kept_first.insert(item);
+            size -= counts[item].0 + counts[item].1;
+        }
+    }
+
+    let item = |e: &usize| rucksack.alphabet.item(*e);
+    let leaving_first = first
+        .iter()
+        .filter(|&&e| !kept_first.contains(e))
+        .map(item)
+        .collect::<Vec<char>>();
+    let leaving_second = second
+        .iter()
+        .filter(|&&e| kept_first.contains(e))
+        .map(item)
+        .collect::<Vec<char>>();
+
+    let swaps = leaving_first.len().min(leaving_second.len());
+
+    let mut moves = leaving_first
+        .iter()
+        .zip(&leaving_second)
+        .map(|(&first, &second)| Move::Swap {
+            rucksack: number,
+            first,
+            second,
+        })
+        .collect::<Vec<Move>>();
+    moves.extend(leaving_first[swaps..].iter().map(|&item| Move::ToSecond {
+        rucksack: number,
+        item,
+    }));
+    moves.extend(leaving_second[swaps..].iter().map(|&item| Move::ToFirst {
+        rucksack: number,
+        item,
+    }));
+
+    Some(moves)
+}
+
+/// Plans every rucksack on its own, numbering them by line from 1.
+pub fn plan(rucksacks: &[Rucksack], capacity: Option<usize>) -> Plan {
+    let mut plan = Plan::default();
+
+    for (index, rucksack) in rucksacks.iter().enumerate() {
+        match plan_rucksack(rucksack, index + 1, capacity) {
+            Some(x) => plan.moves.extend(x),
+            None => plan.infeasible.push(index + 1),
+        }
+    }
+
+    plan
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.moves {
+            match step {
+                Move::Swap {
+                    rucksack,
+                    first,
+                    second,
+                } => writeln!(f, "Rucksack {}: swap {} with {}", rucksack, first, second)?,
+                Move::ToFirst { rucksack, item } => writeln!(
+                    f,
+                    "Rucksack {}: move {} to the first compartment",
+                    rucksack, item
+                )?,
+                Move::ToSecond { rucksack, item } => writeln!(
+                    f,
+                    "Rucksack {}: move {} to the second compartment",
+                    rucksack, item
+                )?,
+            }
+        }
+
+        for rucksack in &self.infeasible {
+            writeln!(
+                f,
+                "Rucksack {}: cannot be separated within capacity",
+                rucksack
+            )?;
+        }
+
+        let count = self.moves.len();
+        writeln!(f, "{} move{}", count, if count == 1 { "" } else { "s" })
+    }
+}
+
+#[test]
+fn test_plan() {
+    let rucksacks = ["abcb", "aaab", "abcd"]
+        .into_iter()
+        .map(Rucksack::from)
+        .collect::<Vec<Rucksack>>();
+
+    let moves = plan_rucksack(&rucksacks[0], 1, None).unwrap();
+    assert_eq!(moves.len(), 1);
+    assert!(matches!(moves[0], Move::Swap { .. }));
+
+    assert_eq!(plan_rucksack(&rucksacks[1], 2, None), None);
+    assert_eq!(
+        plan_rucksack(&rucksacks[1], 2, Some(3)),
+        Some(vec![Move::ToFirst {
+            rucksack: 2,
+            item: 'a'
+        }])
+    );
+    assert_eq!(plan_rucksack(&rucksacks[2], 3, None), Some(vec![]));
+
+    let plan = plan(&rucksacks, None);
+    assert_eq!(plan.infeasible, vec![2]);
+    assert!(plan
+        .to_string()
+        .ends_with("Rucksack 2: cannot be separated within capacity\n1 move\n"));
+}
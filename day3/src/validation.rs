@@ -0,0 +1,151 @@
+use std::{cmp::Reverse, collections::HashMap, fmt};
+
+use crate::Rucksack;
+
+/// Item types listed by `Validation`'s report as most often misplaced.
+pub const MISPLACED_SHOWN: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Anomaly {
+    /// Line of this many characters, which cannot be split evenly.
+    OddLength(usize),
+    Empty,
+    /// More than one item type in both compartments.
+    SeveralShared(Vec<char>),
+    /// Characters outside the alphabet.
+    InvalidItems(Vec<char>),
+}
+
+/// Anomaly of the rucksack on input line `line`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub line: usize,
+    pub anomaly: Anomaly,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Validation {
+    pub findings: Vec<Finding>,
+    /// Item types found in both compartments, with the number of rucksacks they are in both
+    /// compartments of, most often first.
+    pub misplaced: Vec<(char, usize)>,
+}
+
+impl Validation {
+    pub fn is_valid(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+/// Checks rucksacks numbered by line from 1, best parsed with `parse_rucksacks_lenient` so
+/// invalid characters are reported rather than rejected.
+pub fn validate(rucksacks: &[Rucksack]) -> Validation {
+    let mut validation = Validation::default();
+    let mut misplaced: HashMap<char, usize> = HashMap::new();
+
+    for (index, rucksack) in rucksacks.iter().enumerate() {
+        let mut report = |anomaly| {
+            validation.findings.push(Finding {
+                line: index + 1,
+                anomaly,
+            })
+        };
+
+        let length = rucksack.len() + rucksack.invalid_items().len();
+        let shared = rucksack.get_shared_items();
+
+        if length == 0 {
+            report(Anomaly::Empty);
+        } else if length % 2 == 1 {
+            report(Anomaly::OddLength(length));
+        }
+        if shared.len() > 1 {
+            report(Anomaly::SeveralShared(shared.clone()));
+        }
+        if !rucksack.invalid_items().is_empty() {
+            report(Anomaly::InvalidItems(rucksack.invalid_items().to_vec()));
+        }
+
+        for item in shared {
+            *misplaced.entry(item).or_default() += 1;
+        }
+    }
+
+    validation.misplaced = misplaced.into_iter().collect();
+    validation
+        .misplaced
+        .sort_by_key(|&(item, count)| (Reverse(count), item));
+
+    validation
+}
+
+impl fmt::Display for Validation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |items: &[char]| {
+            items
+                .iter()
+                .map(|e| format!("{:?}", e))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        for finding in &self.findings {
+            write!(f, "Line {}: ", finding.line)?;
+
+            match &finding.anomaly {
+                Anomaly::OddLength(x) => writeln!(f, "odd length {}", x)?,
+                Anomaly::Empty => writeln!(f, "empty rucksack")?,
+                Anomaly::SeveralShared(x) => writeln!(f, "several shared item types: {}", list(x))?,
+                Anomaly::InvalidItems(x) => writeln!(f, "invalid items: {}", list(x))?,
+            }
+        }
+
+        if !self.misplaced.is_empty() {
+            let shown = self
+                .misplaced
+                .iter()
+                .take(MISPLACED_SHOWN)
+                .map(|(item, count)| format!("{:?} in {}", item, count))
+                .collect::<Vec<String>>();
+
+            writeln!(f, "Most often misplaced: {}", shown.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_validate() {
+    let input = "abcb\n\nabc\nabab\nab1c!b\n";
+    let validation = validate(&crate::parse_rucksacks_lenient(
+        input,
+        &crate::Alphabet::standard(),
+    ));
+
+    assert_eq!(
+        validation.findings,
+        vec![
+            Finding {
+                line: 2,
+                anomaly: Anomaly::Empty
+            },
+            Finding {
+                line: 3,
+                anomaly: Anomaly::OddLength(3)
+            },
+            Finding {
+                line: 4,
+                anomaly: Anomaly::SeveralShared(vec!['a', 'b'])
+            },
+            Finding {
+                line: 5,
+                anomaly: Anomaly::InvalidItems(vec!['1', '!'])
+            },
+        ]
+    );
+    assert_eq!(validation.misplaced, vec![('b', 3), ('a', 1)]);
+    assert!(validation
+        .to_string()
+        .ends_with("Most often misplaced: 'b' in 3, 'a' in 1\n"));
+}
@@ -0,0 +1,122 @@
+use std::ops::RangeInclusive;
+
+use common::{
+    load, parse_lines, parse_token, token, Answer, InputError, ParseError, Rng, Solution,
+};
+
+pub struct Assignment(RangeInclusive<i32>, RangeInclusive<i32>);
+
+pub fn get_range_from_str(line: &str, value: &str) -> Result<RangeInclusive<i32>, ParseError> {
+    let values = value.split('-').collect::<Vec<&str>>();
+
+    let start = parse_token::<i32>(line, token(line, &values, 0, "range start")?)?;
+    let end = parse_token::<i32>(line, token(line, &values, 1, "range end")?)?;
+
+    Ok(start..=end)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Assignment>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let values = line.split(',').collect::<Vec<&str>>();
+
+            Ok(Assignment(
+                get_range_from_str(line, token(line, &values, 0, "first range")?)?,
+                get_range_from_str(line, token(line, &values, 1, "second range")?)?,
+            ))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        get_number_of_fully_contained_assignments(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        get_number_of_overlapping_assignments(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut range = || {
+            let (a, b) = (rng.range(1..=99), rng.range(1..=99));
+            format!("{}-{}", a.min(b), a.max(b))
+        };
+
+        (0..size.max(1))
+            .map(|_| format!("{},{}\n", range(), range()))
+            .collect()
+    }
+}
+
+pub fn load_assignments(path: &str) -> Result<Vec<Assignment>, InputError> {
+    load::<Day4>(path)
+}
+
+pub fn get_number_of_fully_contained_assignments(assignments: &[Assignment]) -> i32 {
+    let mut out = 0;
+
+    for assignment in assignments {
+        let start = assignment.0.start().min(assignment.1.start());
+        let end = assignment.0.end().max(assignment.1.end());
+        let range = (*start)..=(*end);
+        if assignment.0 == range || assignment.1 == range {
+            out += 1;
+        }
+    }
+
+    out
+}
+
+pub fn get_number_of_overlapping_assignments(assignments: &[Assignment]) -> i32 {
+    let mut out = 0;
+
+    for assignment in assignments {
+        let start = assignment.0.start().min(assignment.1.start());
+        let end = assignment.0.end().max(assignment.1.end());
+        let range = (*start)..=(*end);
+
+        let range_length = range.count();
+        let length0 = assignment.0.clone().count();
+        let length1 = assignment.1.clone().count();
+
+        if range_length < length0 + length1 {
+            out += 1;
+        }
+    }
+
+    out
+}
+
+#[test]
+fn test_example() {
+    let data = vec![
+        "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+    ];
+
+    let mut assignments: Vec<Assignment> = Vec::new();
+
+    for assignments_data in &data {
+        let values = assignments_data.split(",").collect::<Vec<&str>>();
+        assignments.push(Assignment(
+            get_range_from_str(assignments_data, values[0]).unwrap(),
+            get_range_from_str(assignments_data, values[1]).unwrap(),
+        ));
+    }
+
+    assert_eq!(
+        get_number_of_fully_contained_assignments(&assignments),
+        2,
+        "get_number_of_fully_contained_assignments"
+    );
+
+    assert_eq!(
+        get_number_of_overlapping_assignments(&assignments),
+        4,
+        "get_number_of_overlapping_assignments"
+    );
+}
@@ -0,0 +1,353 @@
+use common::{load, parse_token, token, Answer, InputError, ParseError, Rng, Solution};
+
+pub struct Move {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+pub type Stack<T> = Vec<T>;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<Stack<char>>, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut stacks: Vec<Stack<char>> = Vec::new();
+        let mut moves: Vec<Move> = Vec::new();
+
+        let mut stack_data = true;
+        // Crates on each stack before the next move, filled in at the stack number row.
+        let mut heights: Vec<usize> = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let result = if stack_data && line.trim_start().starts_with('[') {
+                parse_crates(line, &mut stacks)
+            } else if stack_data {
+                // The drawing ends with the stack numbers; blank lines follow before the moves.
+                stack_data = false;
+                parse_stack_numbers(line, &mut stacks).map(|_| {
+                    heights = stacks.iter().map(|e| e.len()).collect();
+                })
+            } else if line.is_empty() {
+                Ok(())
+            } else {
+                parse_move(line, &mut heights).map(|e| moves.push(e))
+            };
+
+            result.map_err(|e| e.on_line(index + 1))?;
+        }
+
+        if stack_data && !stacks.is_empty() {
+            return Err(ParseError::missing("", "Missing stack number row")
+                .on_line(input.lines().count() + 1));
+        }
+
+        stacks = stacks
+            .into_iter()
+            .map(|mut e| {
+                e.reverse();
+                e
+            })
+            .collect::<Vec<Stack<char>>>();
+
+        Ok((stacks, moves))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (stacks, moves) = input;
+
+        get_crates_on_top_cm9000(stacks, moves).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (stacks, moves) = input;
+
+        get_crates_on_top_cm9001(stacks, moves).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = rng.range(3..=9) as usize;
+        let mut heights = (0..count)
+            .map(|_| rng.range(1..=8) as usize)
+            .collect::<Vec<usize>>();
+        heights[rng.below(count)] += 1;
+
+        let mut out = String::new();
+
+        for level in (0..*heights.iter().max().unwrap()).rev() {
+            let row = heights
+                .iter()
+                .map(|&e| {
+                    if e > level {
+                        format!("[{}]", char::from(b'A' + rng.below(26) as u8))
+                    } else {
+                        "   ".to_owned()
+                    }
+                })
+                .collect::<Vec<String>>();
+
+            out.push_str(&row.join(" "));
+            out.push('\n');
+        }
+
+        let numbers = (1..=count)
+            .map(|e| format!(" {} ", e))
+            .collect::<Vec<String>>();
+        out.push_str(&numbers.join(" "));
+        out.push_str("\n\n");
+
+        // No stack is ever emptied, so with more crates than stacks some stack can always move.
+        for _ in 0..size.max(1) {
+            let sources = (0..count)
+                .filter(|&e| heights[e] > 1)
+                .collect::<Vec<usize>>();
+            let from = *rng.pick(&sources);
+            let to = (from + 1 + rng.below(count - 1)) % count;
+            let amount = rng.range(1..=heights[from] as i64 - 1) as usize;
+
+            heights[from] -= amount;
+            heights[to] += amount;
+
+            out.push_str(&format!(
+                "move {} from {} to {}\n",
+                amount,
+                from + 1,
+                to + 1
+            ));
+        }
+
+        out
+    }
+}
+
+pub fn load_stacks_with_moves(path: &str) -> Result<(Vec<Stack<char>>, Vec<Move>), InputError> {
+    load::<Day5>(path)
+}
+
+fn parse_crates(line: &str, stacks: &mut Vec<Stack<char>>) -> Result<(), ParseError> {
+    let chars = line.char_indices().collect::<Vec<(usize, char)>>();
+
+    let width = (chars.len() + 1) / 4;
+    if width > stacks.len() {
+        stacks.resize(width, Stack::new());
+    }
+
+    for (index, chunk) in chars.chunks(4).enumerate() {
+        let label = match chunk.get(1) {
+            Some((_, x)) => *x,
+            None => return Err(ParseError::missing(line, "Missing crate label")),
+        };
+
+        if label == ' ' {
+            continue;
+        }
+
+        if chunk[0].1 != '[' {
+            let (start, opening) = chunk[0];
+            return Err(ParseError::at(
+                line,
+                &line[start..start + opening.len_utf8()],
+                "Expected `[` before crate label",
+            ));
+        }
+
+        if index >= stacks.len() {
+            stacks.resize(index + 1, Stack::new());
+        }
+
+        stacks[index].push(label);
+    }
+
+    Ok(())
+}
+
+/// Checks the row numbering the stacks 1, 2, ... under the drawing, adding any stack
+/// holding no crates.
+fn parse_stack_numbers(line: &str, stacks: &mut Vec<Stack<char>>) -> Result<(), ParseError> {
+    let tokens = line.split_whitespace().collect::<Vec<&str>>();
+
+    if tokens.is_empty() {
+        return Err(ParseError::missing(
+            line,
+            "Expected crates or stack numbers",
+        ));
+    }
+
+    for (index, value) in tokens.iter().enumerate() {
+        if value.parse::<usize>().ok() != Some(index + 1) {
+            return Err(ParseError::at(
+                line,
+                value,
+                format!("Expected crates or stack number {}", index + 1),
+            ));
+        }
+    }
+
+    if tokens.len() < stacks.len() {
+        return Err(ParseError::missing(
+            line,
+            format!("Expected {} stack numbers", stacks.len()),
+        ));
+    }
+    stacks.resize(tokens.len(), Stack::new());
+
+    Ok(())
+}
+
+fn parse_stack_index(line: &str, value: &str, stack_count: usize) -> Result<usize, ParseError> {
+    let index = parse_token::<usize>(line, value)?;
+
+    if index == 0 || index > stack_count {
+        return Err(ParseError::at(
+            line,
+            value,
+            format!("Stack must be between 1 and {}", stack_count),
+        ));
+    }
+
+    Ok(index - 1)
+}
+
+/// Parses a move, rejecting it when the source stack holds fewer crates than it moves.
+fn parse_move(line: &str, heights: &mut [usize]) -> Result<Move, ParseError> {
+    let tokens = line.split(' ').collect::<Vec<&str>>();
+
+    for (index, keyword) in [(0, "move"), (2, "from"), (4, "to")] {
+        let value = token(line, &tokens, index, keyword)?;
+        if value != keyword {
+            return Err(ParseError::at(
+                line,
+                value,
+                format!("Expected `{}`", keyword),
+            ));
+        }
+    }
+    if let Some(extra) = tokens.get(6) {
+        return Err(ParseError::at(line, extra, "Unexpected token"));
+    }
+
+    let amount_token = token(line, &tokens, 1, "crate amount")?;
+    let crate_move = Move {
+        amount: parse_token::<usize>(line, amount_token)?,
+        from: parse_stack_index(
+            line,
+            token(line, &tokens, 3, "source stack")?,
+            heights.len(),
+        )?,
+        to: parse_stack_index(
+            line,
+            token(line, &tokens, 5, "target stack")?,
+            heights.len(),
+        )?,
+    };
+
+    if crate_move.amount > heights[crate_move.from] {
+        return Err(ParseError::at(
+            line,
+            amount_token,
+            format!(
+                "Stack {} holds only {} crate(s)",
+                crate_move.from + 1,
+                heights[crate_move.from]
+            ),
+        ));
+    }
+
+    heights[crate_move.from] -= crate_move.amount;
+    heights[crate_move.to] += crate_move.amount;
+
+    Ok(crate_move)
+}
+
+pub fn get_crates_on_top_cm9000(stacks: &[Stack<char>], moves: &[Move]) -> String {
+    let mut temp_stacks = stacks.to_vec();
+
+    for m in moves {
+        for _ in 0..m.amount {
+            if let Some(c) = temp_stacks[m.from].pop() {
+                temp_stacks[m.to].push(c);
+            }
+        }
+    }
+
+    let mut out = String::new();
+
+    for stack in &temp_stacks {
+        if let Some(value) = stack.last() {
+            out.push(*value);
+        }
+    }
+
+    out
+}
+
+pub fn get_crates_on_top_cm9001(stacks: &[Stack<char>], moves: &[Move]) -> String {
+    let mut temp_stacks = stacks.to_vec();
+
+    for m in moves {
+        let mut moved = temp_stacks[m.from]
+            .iter()
+            .rev()
+            .take(m.amount)
+            .rev()
+            .copied()
+            .collect::<Vec<char>>();
+
+        temp_stacks[m.to].append(&mut moved);
+        for _ in 0..m.amount {
+            temp_stacks[m.from].pop();
+        }
+    }
+
+    let mut out = String::new();
+
+    for stack in &temp_stacks {
+        if let Some(value) = stack.last() {
+            out.push(*value);
+        }
+    }
+
+    out
+}
+
+#[test]
+fn test_oversized_move() {
+    let stacks = "[A]    \n[B] [C]\n 1   2 \n\n";
+
+    assert!(Day5::parse(&format!(
+        "{}move 2 from 1 to 2\nmove 3 from 2 to 1\n",
+        stacks
+    ))
+    .is_ok());
+
+    let error = Day5::parse(&format!(
+        "{}move 1 from 2 to 1\nmove 2 from 2 to 1\n",
+        stacks
+    ))
+    .err()
+    .unwrap();
+    assert_eq!((error.line, error.column), (6, 6));
+    assert_eq!(error.message, "Stack 2 holds only 0 crate(s)");
+}
+
+#[test]
+fn test_unrecognised_lines() {
+    let stacks = "[A]    \n[B] [C]\n 1   2 \n\n";
+
+    let error = Day5::parse(&format!("{}mvoe 1 from 1 to 2\n", stacks))
+        .err()
+        .unwrap();
+    assert_eq!((error.line, error.column), (5, 1));
+    assert_eq!(error.message, "Expected `move`");
+
+    let error = Day5::parse("move 1 from 1 to 2\n").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 1));
+    assert_eq!(error.message, "Expected crates or stack number 1");
+
+    let error = Day5::parse("[A]\n\nmove 1 from 1 to 1\n").err().unwrap();
+    assert_eq!(error.line, 2);
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use common::{load, Answer, InputError, ParseError, Rng, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let data = input.strip_suffix('\n').unwrap_or(input);
+        let data = data.strip_suffix('\r').unwrap_or(data);
+
+        if let Some((offset, x)) = data.char_indices().find(|(_, e)| !e.is_ascii_lowercase()) {
+            let message = if x == '\n' {
+                "Expected a single line"
+            } else {
+                "Expected a letter from a to z"
+            };
+
+            return Err(
+                ParseError::at(data, &data[offset..offset + x.len_utf8()], message).on_line(1),
+            );
+        }
+
+        Ok(data.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        find_marker_of_length(input, 4).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        find_marker_of_length(input, 14).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut letters = ('a'..='z').collect::<Vec<char>>();
+        rng.shuffle(&mut letters);
+
+        // Too few distinct letters for a start-of-message marker before the planted one.
+        let alphabet = &letters[..rng.range(4..=13) as usize];
+
+        let mut data = (0..size.max(1))
+            .map(|_| *rng.pick(alphabet))
+            .collect::<String>();
+
+        rng.shuffle(&mut letters);
+        data.extend(&letters[..14]);
+        data.extend((0..rng.below(size + 1)).map(|_| *rng.pick(&letters)));
+
+        data + "\n"
+    }
+}
+
+pub fn load_data(path: &str) -> Result<String, InputError> {
+    load::<Day6>(path)
+}
+
+pub fn find_marker_of_length(data: &str, length: i32) -> i32 {
+    let slice = &data.as_bytes()[0..];
+
+    for (index, window) in slice.windows(length as usize).enumerate() {
+        let mut map: HashMap<u8, i32> = HashMap::new();
+
+        for w in window {
+            if !map.contains_key(w) {
+                map.insert(*w, 1);
+            } else {
+                *map.get_mut(w).unwrap() += 1;
+            }
+        }
+
+        if map.values().all(|&e| e == 1) {
+            return index as i32 + length;
+        }
+    }
+
+    0
+}
+
+#[test]
+fn test_parse() {
+    let data = Day6::parse("abcabcabcabcabcabcab\r\n").unwrap();
+    assert_eq!(data, "abcabcabcabcabcabcab");
+    assert_eq!(find_marker_of_length(&data, 4), 0);
+
+    let error = Day6::parse("abcD\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 4));
+
+    let error = Day6::parse("abcd\nefgh\n").unwrap_err();
+    assert_eq!(
+        (error.column, error.message.as_str()),
+        (5, "Expected a single line")
+    );
+}
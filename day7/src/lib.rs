@@ -0,0 +1,321 @@
+use std::{cell::RefCell, rc::Rc};
+
+use common::{load, parse_token, token, Answer, InputError, ParseError, Rng, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Type {
+    File,
+    Directory,
+}
+
+#[derive(Clone)]
+pub struct Node {
+    name: String,
+    parent: Option<Rc<RefCell<Node>>>,
+    contents: Vec<Rc<RefCell<Node>>>,
+    size: u64,
+    node_type: Type,
+}
+
+impl Node {
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn parent(&self) -> Option<Rc<RefCell<Node>>> {
+        self.parent.clone()
+    }
+
+    fn contents(&self) -> Vec<Rc<RefCell<Node>>> {
+        self.contents.clone()
+    }
+
+    fn add_contents(&mut self, contents: Rc<RefCell<Node>>) {
+        self.contents.push(contents);
+    }
+
+    fn get_type(&self) -> Type {
+        self.node_type
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Rc<RefCell<Node>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let root: Rc<RefCell<Node>> = Rc::new(RefCell::new(Node {
+            name: "/".to_owned(),
+            parent: None,
+            contents: vec![],
+            size: 0,
+            node_type: Type::Directory,
+        }));
+
+        let mut current_node = root.clone();
+
+        for (index, line) in input.lines().enumerate() {
+            current_node =
+                parse_line(line, &root, current_node).map_err(|e| e.on_line(index + 1))?;
+        }
+
+        Ok(root)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        sum_of_at_most_100000(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        find_enough_smallest(input.clone()).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.max(1);
+
+        // Directory 0 is `/`; every other one hangs off an earlier directory.
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); count];
+        let mut names: Vec<String> = vec!["/".to_owned()];
+        for index in 1..count {
+            let parent = rng.below(index);
+            let name = loop {
+                let name = random_name(rng);
+                if children[parent].iter().all(|&e| names[e] != name) {
+                    break name;
+                }
+            };
+
+            children[parent].push(index);
+            names.push(name);
+        }
+
+        let mut files: Vec<Vec<(String, u64)>> = vec![Vec::new(); count];
+        for (index, directory_files) in files.iter_mut().enumerate() {
+            let minimum = if index == 0 { 1 } else { 0 };
+
+            for _ in 0..rng.range(minimum..=4) {
+                let name = loop {
+                    let extension = *rng.pick(&["", ".txt", ".dat", ".log"]);
+                    let name = random_name(rng) + extension;
+                    if children[index].iter().all(|&e| names[e] != name)
+                        && directory_files.iter().all(|e| e.0 != name)
+                    {
+                        break name;
+                    }
+                };
+
+                directory_files.push((name, rng.range(1..=1000) as u64));
+            }
+        }
+
+        // Scale the sizes so the disk is 20M to 70M full; below 40M the update fits already.
+        let total = rng.range(20_000_000..=69_000_000) as u64;
+        let weight = files.iter().flatten().map(|e| e.1).sum::<u64>();
+        for file in files.iter_mut().flatten() {
+            file.1 = (file.1 * total / weight).max(1);
+        }
+
+        let mut out = String::new();
+        write_session(rng, 0, &children, &names, &files, &mut out);
+        out
+    }
+}
+
+pub fn load_directory_structure(path: &str) -> Result<Rc<RefCell<Node>>, InputError> {
+    load::<Day7>(path)
+}
+
+fn parse_line(
+    line: &str,
+    root: &Rc<RefCell<Node>>,
+    mut current_node: Rc<RefCell<Node>>,
+) -> Result<Rc<RefCell<Node>>, ParseError> {
+    let tokens = line.split(' ').collect::<Vec<&str>>();
+
+    match token(line, &tokens, 0, "command or listing")? {
+        "$" => match token(line, &tokens, 1, "command")? {
+            "cd" => match token(line, &tokens, 2, "directory")? {
+                ".." => {
+                    current_node = match current_node.clone().borrow().parent() {
+                        Some(addr) => addr.clone(),
+                        None => current_node.clone(),
+                    };
+                }
+                "/" => {
+                    current_node = root.clone();
+                }
+                x => {
+                    current_node = current_node
+                        .clone()
+                        .borrow()
+                        .contents()
+                        .iter()
+                        .find(|&e| e.borrow().name() == x)
+                        .ok_or_else(|| ParseError::at(line, x, "Unknown directory"))?
+                        .clone();
+                }
+            },
+            "ls" => {}
+            x => return Err(ParseError::at(line, x, "Unknown command")),
+        },
+        "dir" => {
+            current_node
+                .borrow_mut()
+                .add_contents(Rc::new(RefCell::new(Node {
+                    name: token(line, &tokens, 1, "directory name")?.to_owned(),
+                    contents: vec![],
+                    parent: Some(current_node.clone()),
+                    size: 0,
+                    node_type: Type::Directory,
+                })));
+        }
+        _ => {
+            let size = parse_token::<u64>(line, tokens[0])?;
+            current_node
+                .borrow_mut()
+                .add_contents(Rc::new(RefCell::new(Node {
+                    name: token(line, &tokens, 1, "file name")?.to_owned(),
+                    size,
+                    parent: Some(current_node.clone()),
+                    contents: vec![],
+                    node_type: Type::File,
+                })));
+
+            let mut current_parent = Some(current_node.clone());
+            while let Some(x) = current_parent.clone() {
+                let total = x.borrow().size.checked_add(size);
+                x.borrow_mut().size = total
+                    .ok_or_else(|| ParseError::at(line, tokens[0], "Directory size overflows"))?;
+                current_parent = x.borrow().parent();
+            }
+        }
+    }
+
+    Ok(current_node)
+}
+
+fn random_name(rng: &mut Rng) -> String {
+    (0..rng.range(1..=8))
+        .map(|_| char::from(b'a' + rng.below(26) as u8))
+        .collect()
+}
+
+/// Appends the `cd`/`ls` session exploring `directory` and everything below it.
+fn write_session(
+    rng: &mut Rng,
+    directory: usize,
+    children: &[Vec<usize>],
+    names: &[String],
+    files: &[Vec<(String, u64)>],
+    out: &mut String,
+) {
+    out.push_str(&format!("$ cd {}\n$ ls\n", names[directory]));
+
+    let mut listing = children[directory]
+        .iter()
+        .map(|&e| format!("dir {}\n", names[e]))
+        .chain(
+            files[directory]
+                .iter()
+                .map(|(name, size)| format!("{} {}\n", size, name)),
+        )
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut listing);
+    out.push_str(&listing.concat());
+
+    for &child in &children[directory] {
+        write_session(rng, child, children, names, files, out);
+        out.push_str("$ cd ..\n");
+    }
+}
+
+pub fn sum_of_at_most_100000(root: Rc<RefCell<Node>>) -> u64 {
+    let mut stack: Vec<Rc<RefCell<Node>>> = Vec::new();
+    stack.push(root.clone());
+
+    let mut sum = 0;
+
+    while let Some(top) = stack.pop() {
+        if top.borrow().get_type() == Type::Directory {
+            for content in top.borrow().contents() {
+                if content.borrow().node_type == Type::Directory {
+                    stack.push(content.clone());
+                }
+            }
+        }
+
+        if top.borrow().size() <= 100000 {
+            sum += top.borrow().size();
+        }
+        // println!("dir {} (size={})", top.borrow().name(), top.borrow().size());
+    }
+
+    sum
+}
+
+/// Size of the smallest directory freeing enough space for the update, or 0 when there is
+/// enough free space already.
+pub fn find_enough_smallest(root: Rc<RefCell<Node>>) -> u64 {
+    let mut stack: Vec<Rc<RefCell<Node>>> = Vec::new();
+    stack.push(root.clone());
+
+    let free_space = 70000000u64.saturating_sub(root.borrow().size());
+    let space_needed = 30000000u64.saturating_sub(free_space);
+
+    if space_needed == 0 {
+        return 0;
+    }
+
+    let mut least_enough = 0;
+    let mut least_difference = u64::MAX;
+
+    while let Some(top) = stack.pop() {
+        if top.borrow().get_type() == Type::Directory {
+            for content in top.borrow().contents() {
+                if content.borrow().node_type == Type::Directory {
+                    stack.push(content.clone());
+                }
+            }
+        }
+
+        if space_needed <= top.borrow().size()
+            && top.borrow().size() - space_needed < least_difference
+        {
+            least_difference = top.borrow().size() - space_needed;
+            least_enough = top.borrow().size();
+        }
+    }
+
+    least_enough
+}
+
+#[test]
+fn test_small_inputs() {
+    for input in ["", "$ ls\n", "$ cd /\n$ ls\n100 a\n"] {
+        let root = Day7::parse(input).unwrap();
+
+        assert_eq!(find_enough_smallest(root), 0);
+    }
+
+    let root = Day7::parse("$ cd /\n$ ls\ndir a\n40000000 b\n$ cd a\n$ ls\n5000 c\n").unwrap();
+    assert_eq!(find_enough_smallest(root.clone()), 5000);
+    assert_eq!(sum_of_at_most_100000(root), 5000);
+
+    let root = Day7::parse("$ cd /\n$ ls\n4000000000 a\n4000000000 b\n").unwrap();
+    assert_eq!(root.borrow().size(), 8000000000);
+
+    let error = Day7::parse(&format!("$ ls\n{} a\n1 b\n", u64::MAX))
+        .err()
+        .unwrap();
+    assert_eq!(
+        (error.line, error.message.as_str()),
+        (3, "Directory size overflows")
+    );
+}
@@ -0,0 +1,146 @@
+use std::str::FromStr;
+
+use common::{load, parse_lines, parse_token, Answer, InputError, ParseError, Rng, Solution};
+
+pub enum Move {
+    Up(i32),
+    Down(i32),
+    Left(i32),
+    Right(i32),
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<_> = s.split(' ').collect();
+
+        if tokens.len() != 2 {
+            return Err(ParseError::at(s, s, "Wrong format"));
+        }
+
+        let distance = parse_token::<i32>(s, tokens[1])?;
+        if distance < 1 {
+            return Err(ParseError::at(s, tokens[1], "Distance must be at least 1"));
+        }
+
+        match tokens[0] {
+            "U" => Ok(Move::Up(distance)),
+            "D" => Ok(Move::Down(distance)),
+            "L" => Ok(Move::Left(distance)),
+            "R" => Ok(Move::Right(distance)),
+            _ => Err(ParseError::at(s, tokens[0], "Unknown move")),
+        }
+    }
+}
+
+#[derive(Default, PartialEq, Clone, Copy)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Move::from_str)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        count_unique_tail_positions(input, 1).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        count_unique_tail_positions(input, 9).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.pick(&["U", "D", "L", "R"]),
+                    rng.range(1..=19)
+                )
+            })
+            .collect()
+    }
+}
+
+pub fn load_moves(path: &str) -> Result<Vec<Move>, InputError> {
+    load::<Day9>(path)
+}
+
+pub fn count_unique_tail_positions(moves: &[Move], length: i32) -> i32 {
+    let mut knots_positions: Vec<Position> = Vec::new();
+
+    for _ in 0..(length + 1) {
+        knots_positions.push(Position::default());
+    }
+
+    let mut tail_positions: Vec<Position> = vec![*knots_positions.last().unwrap()];
+
+    for move_info in moves {
+        let distance = match move_info {
+            Move::Up(d) => *d,
+            Move::Down(d) => *d,
+            Move::Left(d) => *d,
+            Move::Right(d) => *d,
+        };
+
+        for _ in 0..distance {
+            match move_info {
+                Move::Up(_) => knots_positions.first_mut().unwrap().y += 1,
+                Move::Down(_) => knots_positions.first_mut().unwrap().y -= 1,
+                Move::Left(_) => knots_positions.first_mut().unwrap().x -= 1,
+                Move::Right(_) => knots_positions.first_mut().unwrap().x += 1,
+            };
+
+            for knot_index in 1..knots_positions.len() {
+                let delta_x: i32;
+                let delta_y: i32;
+
+                {
+                    let previous = knots_positions.get(knot_index - 1).unwrap();
+                    let current = knots_positions.get(knot_index).unwrap();
+
+                    if previous.x.abs_diff(current.x) > 1 || previous.y.abs_diff(current.y) > 1 {
+                        delta_x = (previous.x - current.x).clamp(-1, 1);
+                        delta_y = (previous.y - current.y).clamp(-1, 1);
+                    } else {
+                        delta_x = 0;
+                        delta_y = 0;
+                    }
+                }
+
+                let current = knots_positions.get_mut(knot_index).unwrap();
+
+                current.x += delta_x;
+                current.y += delta_y;
+            }
+
+            if !tail_positions.contains(knots_positions.last().unwrap()) {
+                tail_positions.push(*knots_positions.last().unwrap());
+            }
+        }
+    }
+
+    tail_positions.len() as i32
+}
+
+#[test]
+fn test_non_positive_distance() {
+    for (input, column) in [("R 4\nU -5\n", 3), ("L 0\n", 3)] {
+        let error = input.lines().last().unwrap().parse::<Move>().err().unwrap();
+
+        assert_eq!(column, error.column, "{}", input);
+        assert_eq!(error.message, "Distance must be at least 1");
+    }
+
+    assert!(Day9::parse("R 4\nU 5\n").is_ok());
+}
@@ -0,0 +1,219 @@
+use std::{
+    fs,
+    hint::black_box,
+    io,
+    time::{Duration, Instant},
+};
+
+use common::{Json, ParseError, Solution};
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Times parsing and both parts of `S` separately, `iterations` times each.
+pub fn measure<S: Solution>(
+    input: &str,
+    iterations: usize,
+) -> Result<[Vec<Duration>; 3], ParseError> {
+    let parsed = S::parse(input)?;
+
+    // Warm up caches and lazy allocations before the timed runs.
+    black_box(S::part1(&parsed));
+    black_box(S::part2(&parsed));
+
+    let mut timings: [Vec<Duration>; 3] = Default::default();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(S::parse(black_box(input))?);
+        timings[0].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(black_box(&parsed)));
+        timings[1].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(black_box(&parsed)));
+        timings[2].push(start.elapsed());
+    }
+
+    Ok(timings)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn new(timings: &[Duration]) -> Self {
+        let mut sorted = timings.to_vec();
+        sorted.sort();
+
+        Stats {
+            min: percentile(&sorted, 0.0),
+            median: percentile(&sorted, 50.0),
+            p95: percentile(&sorted, 95.0),
+        }
+    }
+}
+
+/// Nearest-rank percentile of already sorted `timings`.
+fn percentile(sorted: &[Duration], percent: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+
+    let rank = (percent / 100.0 * sorted.len() as f64).ceil() as usize;
+
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BenchResult {
+    pub day: u8,
+    pub stage: String,
+    pub stats: Stats,
+}
+
+impl BenchResult {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("day".to_owned(), Json::Integer(self.day as i64)),
+            ("stage".to_owned(), self.stage.as_str().into()),
+            ("min_ns".to_owned(), nanos(self.stats.min).into()),
+            ("median_ns".to_owned(), nanos(self.stats.median).into()),
+            ("p95_ns".to_owned(), nanos(self.stats.p95).into()),
+        ])
+    }
+
+    fn from_json(value: &Json) -> Option<Self> {
+        let duration = |key: &str| {
+            value
+                .get(key)?
+                .as_i64()
+                .map(|e| Duration::from_nanos(e as u64))
+        };
+
+        Some(BenchResult {
+            day: value.get("day")?.as_i64()? as u8,
+            stage: value.get("stage")?.as_str()?.to_owned(),
+            stats: Stats {
+                min: duration("min_ns")?,
+                median: duration("median_ns")?,
+                p95: duration("p95_ns")?,
+            },
+        })
+    }
+}
+
+fn nanos(duration: Duration) -> i64 {
+    duration.as_nanos().min(i64::MAX as u128) as i64
+}
+
+/// Reads a saved baseline; a missing file is an empty baseline.
+pub fn load_baseline(path: &str) -> Result<Vec<BenchResult>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(x) => x,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Cannot read {}: {}", path, e)),
+    };
+
+    let json = Json::parse(&text).map_err(|e| e.in_file(path).to_string())?;
+
+    json.get("results")
+        .and_then(Json::as_array)
+        .ok_or_else(|| format!("{}: missing `results` array", path))?
+        .iter()
+        .map(|e| {
+            BenchResult::from_json(e).ok_or_else(|| format!("{}: malformed result {}", path, e))
+        })
+        .collect()
+}
+
+/// Writes `results` over `previous`, keeping previous entries for days that were not benchmarked.
+pub fn save_baseline(
+    path: &str,
+    previous: &[BenchResult],
+    results: &[BenchResult],
+) -> Result<(), String> {
+    let mut merged = previous
+        .iter()
+        .filter(|e| !results.iter().any(|r| r.day == e.day && r.stage == e.stage))
+        .chain(results.iter())
+        .cloned()
+        .collect::<Vec<BenchResult>>();
+
+    merged.sort_by(|a, b| (a.day, &a.stage).cmp(&(b.day, &b.stage)));
+
+    let json = Json::Object(vec![(
+        "results".to_owned(),
+        Json::Array(merged.iter().map(BenchResult::to_json).collect()),
+    )]);
+
+    fs::write(path, json.pretty() + "\n").map_err(|e| format!("Cannot write {}: {}", path, e))
+}
+
+/// Relative change of the median against `baseline`, e.g. `0.25` for 25% slower.
+pub fn change(result: &BenchResult, baseline: &[BenchResult]) -> Option<f64> {
+    let previous = baseline
+        .iter()
+        .find(|e| e.day == result.day && e.stage == result.stage)?;
+
+    if previous.stats.median.is_zero() {
+        return None;
+    }
+
+    Some(result.stats.median.as_secs_f64() / previous.stats.median.as_secs_f64() - 1.0)
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[test]
+fn test_stats() {
+    let timings = (1..=20)
+        .rev()
+        .map(Duration::from_micros)
+        .collect::<Vec<Duration>>();
+
+    let stats = Stats::new(&timings);
+
+    assert_eq!(stats.min, Duration::from_micros(1));
+    assert_eq!(stats.median, Duration::from_micros(10));
+    assert_eq!(stats.p95, Duration::from_micros(19));
+}
+
+#[test]
+fn test_change_against_baseline() {
+    let result = |day, median| BenchResult {
+        day,
+        stage: "part1".to_owned(),
+        stats: Stats {
+            min: Duration::ZERO,
+            median: Duration::from_micros(median),
+            p95: Duration::ZERO,
+        },
+    };
+
+    let baseline = vec![result(1, 100)];
+
+    assert!((change(&result(1, 150), &baseline).unwrap() - 0.5).abs() < 1e-9);
+    assert_eq!(change(&result(2, 150), &baseline), None);
+    assert_eq!(
+        BenchResult::from_json(&result(1, 100).to_json()),
+        Some(result(1, 100))
+    );
+}
@@ -1,3 +1,484 @@
+use std::{env, process, time::Duration};
+
+use common::{
+    default_provider, input_dir, input_name, read_input, solve, ParseError, Record, Rng, Solution,
+};
+
+mod bench;
+mod output;
+mod verify;
+
+use bench::{BenchResult, Stats};
+use output::Format;
+use verify::Status;
+
+type Solve = fn(&str, Option<u8>) -> Result<Vec<Record>, ParseError>;
+
+type Measure = fn(&str, usize) -> Result<[Vec<Duration>; 3], ParseError>;
+
+type Generate = fn(&mut Rng, usize) -> String;
+
+struct Day {
+    number: u8,
+    solve: Solve,
+    measure: Measure,
+    generate: Generate,
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        number: S::DAY,
+        solve: solve::<S>,
+        measure: bench::measure::<S>,
+        generate: S::generate,
+    }
+}
+
+const DAYS: [Day; 10] = [
+    day::<day1::Day1>(),
+    day::<day2::Day2>(),
+    day::<day3::Day3>(),
+    day::<day4::Day4>(),
+    day::<day5::Day5>(),
+    day::<day6::Day6>(),
+    day::<day7::Day7>(),
+    day::<day8::Day8>(),
+    day::<day9::Day9>(),
+    day::<day10::Day10>(),
+];
+
+const USAGE: &str = "Usage:
+    aoc2022 run <day> [--part 1|2] [--input <path|->] [--format text|json|csv]
+    aoc2022 run --all [--part 1|2] [--format text|json|csv]
+    aoc2022 bench [day] [--iterations N] [--baseline <path>] [--threshold <percent>] [--no-save] [--accept]
+    aoc2022 verify [--dir <path>] [--answers <path>]
+    aoc2022 gen <day> [--seed N] [--size M]
+
+With --format json or csv, run reports each answer's type, parse time and solve time.
+Inputs default to dayN/input.txt under $AOC_INPUT_DIR, or under the current directory;
+with $AOC_USER set they are read from dayN/<user>.txt instead. When $AOC_SESSION holds a
+session token, missing inputs are downloaded from $AOC_BASE_URL (adventofcode.com) and kept.
+verify checks every dayN/<name>.txt there against the answers recorded in --answers,
+by default answers.json in the current directory, or in --dir when one is given;
+`aoc2022 verify --dir fixtures` checks the puzzle examples.
+bench saves its numbers as the new baseline, unless a stage regressed and --accept is not given.
+gen prints a random input for the day; the same seed and size always give the same input.";
+
+const DEFAULT_BASELINE: &str = "bench-baseline.json";
+
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl Selection {
+    fn days(&self) -> impl Iterator<Item = &'static Day> + '_ {
+        DAYS.iter().filter(move |e| match self {
+            Selection::All => true,
+            Selection::Day(number) => e.number == *number,
+        })
+    }
+}
+
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Verify(VerifyArgs),
+    Gen(GenArgs),
+}
+
+struct RunArgs {
+    selection: Selection,
+    part: Option<u8>,
+    input: Option<String>,
+    format: Format,
+}
+
+struct BenchArgs {
+    selection: Selection,
+    iterations: usize,
+    baseline: String,
+    threshold: f64,
+    save: bool,
+    accept: bool,
+}
+
+struct VerifyArgs {
+    dir: String,
+    answers: String,
+    /// Whether `dir` holds personal inputs, checked as `input` besides the registered ones.
+    personal: bool,
+}
+
+struct GenArgs {
+    day: u8,
+    seed: u64,
+    size: usize,
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    let number = value
+        .parse::<u8>()
+        .map_err(|_| format!("Unknown argument: {}", value))?;
+
+    if !DAYS.iter().any(|e| e.number == number) {
+        return Err(format!("Day {} is not solved yet", number));
+    }
+
+    Ok(number)
+}
+
+fn parse_value<'a>(
+    iter: &mut impl Iterator<Item = &'a String>,
+    option: &str,
+) -> Result<&'a String, String> {
+    iter.next()
+        .ok_or_else(|| format!("Missing value for {}", option))
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut selection: Option<Selection> = None;
+    let mut part: Option<u8> = None;
+    let mut input: Option<String> = None;
+    let mut format = Format::Text;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--all" => selection = Some(Selection::All),
+            "--part" => {
+                let value = parse_value(&mut iter, "--part")?;
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("Unknown part: {}", value)),
+                };
+            }
+            "--input" => input = Some(parse_value(&mut iter, "--input")?.clone()),
+            "--format" => format = parse_value(&mut iter, "--format")?.parse()?,
+            x => selection = Some(Selection::Day(parse_day(x)?)),
+        }
+    }
+
+    let selection = selection.ok_or("Missing day")?;
+
+    if input.is_some() && matches!(selection, Selection::All) {
+        return Err("--input can only be used with a single day".to_owned());
+    }
+
+    Ok(RunArgs {
+        selection,
+        part,
+        input,
+        format,
+    })
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        selection: Selection::All,
+        iterations: 100,
+        baseline: DEFAULT_BASELINE.to_owned(),
+        threshold: 10.0,
+        save: true,
+        accept: false,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                let value = parse_value(&mut iter, "--iterations")?;
+                bench_args.iterations = match value.parse::<usize>() {
+                    Ok(x) if x > 0 => x,
+                    _ => return Err(format!("Invalid iteration count: {}", value)),
+                };
+            }
+            "--baseline" => bench_args.baseline = parse_value(&mut iter, "--baseline")?.clone(),
+            "--threshold" => {
+                let value = parse_value(&mut iter, "--threshold")?;
+                bench_args.threshold = match value.parse::<f64>() {
+                    Ok(x) if x >= 0.0 => x,
+                    _ => return Err(format!("Invalid threshold: {}", value)),
+                };
+            }
+            "--no-save" => bench_args.save = false,
+            "--accept" => bench_args.accept = true,
+            x => bench_args.selection = Selection::Day(parse_day(x)?),
+        }
+    }
+
+    Ok(bench_args)
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut dir: Option<String> = None;
+    let mut answers: Option<String> = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--dir" => dir = Some(parse_value(&mut iter, "--dir")?.clone()),
+            "--answers" => answers = Some(parse_value(&mut iter, "--answers")?.clone()),
+            x => return Err(format!("Unknown argument: {}", x)),
+        }
+    }
+
+    // Personal answers live in the top-level registry wherever the inputs are kept.
+    let answers = answers.unwrap_or_else(|| match &dir {
+        Some(x) => verify::registry_path(x),
+        None => verify::REGISTRY_FILE.to_owned(),
+    });
+
+    Ok(VerifyArgs {
+        personal: dir.is_none(),
+        dir: dir.unwrap_or_else(input_dir),
+        answers,
+    })
+}
+
+fn parse_gen_args(args: &[String]) -> Result<GenArgs, String> {
+    let mut day: Option<u8> = None;
+    let mut seed: u64 = 0;
+    let mut size: usize = 100;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = parse_value(&mut iter, "--seed")?;
+                seed = value
+                    .parse::<u64>()
+                    .map_err(|_| format!("Invalid seed: {}", value))?;
+            }
+            "--size" => {
+                let value = parse_value(&mut iter, "--size")?;
+                size = match value.parse::<usize>() {
+                    Ok(x) if x > 0 => x,
+                    _ => return Err(format!("Invalid size: {}", value)),
+                };
+            }
+            x => day = Some(parse_day(x)?),
+        }
+    }
+
+    Ok(GenArgs {
+        day: day.ok_or("Missing day")?,
+        seed,
+        size,
+    })
+}
+
+fn load_input(day: &Day, path: Option<&String>) -> Result<(String, String), String> {
+    match path {
+        Some(path) => {
+            let input = read_input(path).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+
+            Ok((path.clone(), input))
+        }
+        None => {
+            let provider = default_provider();
+            let input = provider.fetch(day.number).map_err(|e| e.to_string())?;
+
+            Ok((provider.location(day.number), input))
+        }
+    }
+}
+
+fn solve_days(args: &RunArgs, records: &mut Vec<Record>) -> Result<(), String> {
+    for day in args.selection.days() {
+        let (path, input) = load_input(day, args.input.as_ref())?;
+
+        let day_records =
+            (day.solve)(&input, args.part).map_err(|e| e.in_file(input_name(&path)).to_string())?;
+
+        records.extend(day_records);
+    }
+
+    Ok(())
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let mut records: Vec<Record> = Vec::new();
+
+    // Days solved before a failure are still reported.
+    let result = solve_days(&args, &mut records);
+
+    print!("{}", output::render(&records, args.format));
+
+    result
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = bench::load_baseline(&args.baseline)?;
+
+    let mut results: Vec<BenchResult> = Vec::new();
+    let mut regressions = 0;
+
+    println!(
+        "{:<4} {:<6} {:>10} {:>10} {:>10}  Change",
+        "Day", "Stage", "Min", "Median", "P95"
+    );
+
+    for day in args.selection.days() {
+        let (path, input) = load_input(day, None)?;
+
+        let timings = (day.measure)(&input, args.iterations)
+            .map_err(|e| e.in_file(input_name(&path)).to_string())?;
+
+        for (stage, stage_timings) in bench::STAGES.iter().zip(timings.iter()) {
+            let result = BenchResult {
+                day: day.number,
+                stage: stage.to_string(),
+                stats: Stats::new(stage_timings),
+            };
+
+            let change = match bench::change(&result, &baseline) {
+                Some(x) if x * 100.0 > args.threshold => {
+                    regressions += 1;
+                    format!("{:+.1}% REGRESSION", x * 100.0)
+                }
+                Some(x) => format!("{:+.1}%", x * 100.0),
+                None => "new".to_owned(),
+            };
+
+            println!(
+                "{:<4} {:<6} {:>10} {:>10} {:>10}  {}",
+                result.day,
+                result.stage,
+                bench::format_duration(result.stats.min),
+                bench::format_duration(result.stats.median),
+                bench::format_duration(result.stats.p95),
+                change
+            );
+
+            results.push(result);
+        }
+    }
+
+    // A regressed run only replaces the baseline when its numbers are accepted explicitly.
+    if args.save && (regressions == 0 || args.accept) {
+        bench::save_baseline(&args.baseline, &baseline, &results)?;
+    }
+
+    if regressions > 0 && !args.accept {
+        return Err(format!(
+            "{} stage(s) regressed by more than {}% against {}; baseline kept, \
+             rerun with --accept to save these numbers",
+            regressions, args.threshold, args.baseline
+        ));
+    }
+
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let registry = verify::load_registry(&args.answers)?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for check in verify::check_dir(&args.dir, &DAYS, &registry, args.personal) {
+        let label = format!("Day {} part {} [{}]", check.day, check.part, check.input);
+
+        match check.status {
+            Status::Pass => {
+                passed += 1;
+                println!("{}: pass", label);
+            }
+            Status::Fail(reason) => {
+                failed += 1;
+                println!("{}: FAIL ({})", label, reason);
+            }
+            Status::Missing(reason) => {
+                missing += 1;
+                println!("{}: missing ({})", label, reason);
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if failed > 0 {
+        return Err(format!(
+            "{} answer(s) do not match {}",
+            failed, args.answers
+        ));
+    }
+
+    Ok(())
+}
+
+fn generate(args: GenArgs) -> Result<(), String> {
+    let day = Selection::Day(args.day).days().next().unwrap();
+
+    print!("{}", (day.generate)(&mut Rng::new(args.seed), args.size));
+
+    Ok(())
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    let command = match args.first().map(|e| e.as_str()) {
+        Some("run") => parse_run_args(&args[1..]).map(Command::Run),
+        Some("bench") => parse_bench_args(&args[1..]).map(Command::Bench),
+        Some("verify") => parse_verify_args(&args[1..]).map(Command::Verify),
+        Some("gen") => parse_gen_args(&args[1..]).map(Command::Gen),
+        Some(x) => Err(format!("Unknown command: {}", x)),
+        None => Err("Missing command".to_owned()),
+    };
+
+    let command = command.unwrap_or_else(|message| {
+        eprintln!("{}\n\n{}", message, USAGE);
+        process::exit(2);
+    });
+
+    let result = match command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Gen(args) => generate(args),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+#[test]
+fn test_generated_inputs() {
+    for day in &DAYS {
+        for (seed, size) in [(0, 1), (1, 10), (2, 200)] {
+            let input = (day.generate)(&mut Rng::new(seed), size);
+
+            assert_eq!(input, (day.generate)(&mut Rng::new(seed), size));
+
+            if let Err(e) = (day.solve)(&input, None) {
+                panic!(
+                    "Day {} seed {} size {}: {}\n{}",
+                    day.number, seed, size, e, input
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_verify_registry_paths() {
+    let args = |e: &[&str]| e.iter().map(|e| e.to_string()).collect::<Vec<String>>();
+
+    assert_eq!(parse_verify_args(&[]).unwrap().answers, "answers.json");
+    assert_eq!(
+        parse_verify_args(&args(&["--dir", "fixtures"]))
+            .unwrap()
+            .answers,
+        "fixtures/answers.json"
+    );
+    assert_eq!(
+        parse_verify_args(&args(&["--dir", "fixtures", "--answers", "mine.json"]))
+            .unwrap()
+            .answers,
+        "mine.json"
+    );
 }
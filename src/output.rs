@@ -0,0 +1,139 @@
+use std::{str::FromStr, time::Duration};
+
+use common::{csv_field, Answer, Json, Record};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            x => Err(format!("Unknown format: {}", x)),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "day,part,answer,type,parse_ns,solve_ns";
+
+fn answer_type(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::Number(_) => "number",
+        Answer::Text(_) => "text",
+    }
+}
+
+fn nanos(duration: Duration) -> i64 {
+    duration.as_nanos().min(i64::MAX as u128) as i64
+}
+
+fn to_json(record: &Record) -> Json {
+    let answer = match &record.answer {
+        Answer::Number(x) => Json::Integer(*x),
+        Answer::Text(x) => x.as_str().into(),
+    };
+
+    Json::Object(vec![
+        ("day".to_owned(), Json::Integer(record.day as i64)),
+        ("part".to_owned(), Json::Integer(record.part as i64)),
+        ("answer".to_owned(), answer),
+        ("type".to_owned(), answer_type(&record.answer).into()),
+        ("parse_ns".to_owned(), nanos(record.parse_time).into()),
+        ("solve_ns".to_owned(), nanos(record.solve_time).into()),
+    ])
+}
+
+fn to_text(record: &Record) -> String {
+    let answer = record.answer.to_string();
+
+    if answer.contains('\n') {
+        format!(
+            "Day {} part {}:\n{}\n",
+            record.day,
+            record.part,
+            answer.trim_end()
+        )
+    } else {
+        format!("Day {} part {}: {}\n", record.day, record.part, answer)
+    }
+}
+
+/// Renders `records` as a complete document in `format`.
+pub fn render(records: &[Record], format: Format) -> String {
+    match format {
+        Format::Text => records.iter().map(to_text).collect(),
+        Format::Json => {
+            let json = Json::Object(vec![(
+                "results".to_owned(),
+                Json::Array(records.iter().map(to_json).collect()),
+            )]);
+
+            json.pretty() + "\n"
+        }
+        Format::Csv => {
+            let mut out = format!("{}\n", CSV_HEADER);
+
+            for record in records {
+                out.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    record.day,
+                    record.part,
+                    csv_field(&record.answer.to_string()),
+                    answer_type(&record.answer),
+                    nanos(record.parse_time),
+                    nanos(record.solve_time)
+                ));
+            }
+
+            out
+        }
+    }
+}
+
+#[test]
+fn test_render() {
+    let record = |part, answer: &str| Record {
+        day: 10,
+        part,
+        answer: match answer.parse::<i64>() {
+            Ok(x) => Answer::Number(x),
+            Err(_) => Answer::from(answer),
+        },
+        parse_time: Duration::from_nanos(1500),
+        solve_time: Duration::from_nanos(250),
+    };
+
+    let records = vec![record(1, "13140"), record(2, "#.\n.#\n")];
+
+    assert_eq!(
+        render(&records, Format::Csv),
+        "day,part,answer,type,parse_ns,solve_ns\n\
+         10,1,13140,number,1500,250\n\
+         10,2,\"#.\n.#\n\",text,1500,250\n"
+    );
+    assert_eq!(
+        render(&records, Format::Text),
+        "Day 10 part 1: 13140\nDay 10 part 2:\n#.\n.#\n"
+    );
+
+    let json = Json::parse(&render(&records, Format::Json)).unwrap();
+    let results = json.get("results").and_then(Json::as_array).unwrap();
+
+    assert_eq!(
+        results[1].get("answer").and_then(Json::as_str),
+        Some("#.\n.#\n")
+    );
+    assert_eq!(results[1].get("type").and_then(Json::as_str), Some("text"));
+    assert_eq!(
+        results[0].get("parse_ns").and_then(Json::as_i64),
+        Some(1500)
+    );
+}
@@ -0,0 +1,217 @@
+use std::{fs, io};
+
+use common::{input_path, read_input, Answer, Json};
+
+use crate::Day;
+
+pub const REGISTRY_FILE: &str = "answers.json";
+
+/// Expected answer for one part of one day, on the input called `input`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Answer,
+}
+
+impl Expected {
+    fn from_json(value: &Json) -> Option<Self> {
+        let answer = match value.get("answer")? {
+            Json::String(x) => Answer::Text(x.clone()),
+            x => Answer::Number(x.as_i64()?),
+        };
+
+        Some(Expected {
+            day: value.get("day")?.as_i64()? as u8,
+            part: value.get("part")?.as_i64()? as u8,
+            input: value.get("input")?.as_str()?.to_owned(),
+            answer,
+        })
+    }
+}
+
+pub fn registry_path(dir: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches('/'), REGISTRY_FILE)
+}
+
+pub fn load_registry(path: &str) -> Result<Vec<Expected>, String> {
+    let text = fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => format!(
+            "No answer registry at {}; create it or pass --answers <path>",
+            path
+        ),
+        _ => format!("Cannot read {}: {}", path, e),
+    })?;
+
+    let json = Json::parse(&text).map_err(|e| e.in_file(path).to_string())?;
+
+    json.get("answers")
+        .and_then(Json::as_array)
+        .ok_or_else(|| format!("{}: missing `answers` array", path))?
+        .iter()
+        .map(|e| Expected::from_json(e).ok_or_else(|| format!("{}: malformed answer {}", path, e)))
+        .collect()
+}
+
+/// Names of the inputs to check for `day`: every registered one, plus `input` in a
+/// `personal` input directory.
+pub fn input_names(registry: &[Expected], day: u8, personal: bool) -> Vec<String> {
+    let mut names = Vec::new();
+    if personal {
+        names.push("input".to_owned());
+    }
+
+    for expected in registry.iter().filter(|e| e.day == day) {
+        if !names.contains(&expected.input) {
+            names.push(expected.input.clone());
+        }
+    }
+
+    names
+}
+
+pub fn find<'a>(registry: &'a [Expected], day: u8, part: u8, input: &str) -> Option<&'a Answer> {
+    registry
+        .iter()
+        .find(|e| e.day == day && e.part == part && e.input == input)
+        .map(|e| &e.answer)
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Missing(String),
+}
+
+pub fn check(expected: Option<&Answer>, actual: &Answer) -> Status {
+    match expected {
+        Some(x) if x == actual => Status::Pass,
+        Some(x) => Status::Fail(format!(
+            "expected {:?}, got {:?}",
+            x.to_string(),
+            actual.to_string()
+        )),
+        None => Status::Missing(format!("no recorded answer, got {:?}", actual.to_string())),
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub status: Status,
+}
+
+/// Solves every input of `days` found under `dir` and checks the answers against `registry`.
+pub fn check_dir(dir: &str, days: &[Day], registry: &[Expected], personal: bool) -> Vec<Check> {
+    let mut checks: Vec<Check> = Vec::new();
+
+    for day in days {
+        for name in input_names(registry, day.number, personal) {
+            let path = input_path(dir, day.number, &name);
+
+            let statuses: Vec<(u8, Status)> = match read_input(&path) {
+                Err(e) => (1..=2)
+                    .map(|part| {
+                        (
+                            part,
+                            Status::Missing(format!("cannot read {}: {}", path, e)),
+                        )
+                    })
+                    .collect(),
+                Ok(input) => match (day.solve)(&input, None) {
+                    Err(e) => (1..=2)
+                        .map(|part| (part, Status::Fail(e.clone().in_file(&path).to_string())))
+                        .collect(),
+                    Ok(records) => records
+                        .iter()
+                        .map(|e| {
+                            let expected = find(registry, day.number, e.part, &name);
+                            (e.part, check(expected, &e.answer))
+                        })
+                        .collect(),
+                },
+            };
+
+            checks.extend(statuses.into_iter().map(|(part, status)| Check {
+                day: day.number,
+                part,
+                input: name.clone(),
+                status,
+            }));
+        }
+    }
+
+    checks
+}
+
+#[test]
+fn test_registry_entries() {
+    let json = Json::parse(
+        r#"[{"day": 1, "part": 2, "input": "example", "answer": 45000},
+            {"day": 5, "part": 1, "input": "example", "answer": "CMZ"}]"#,
+    )
+    .unwrap();
+
+    let registry = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| Expected::from_json(e).unwrap())
+        .collect::<Vec<Expected>>();
+
+    assert_eq!(input_names(&registry, 1, true), vec!["input", "example"]);
+    assert_eq!(input_names(&registry, 1, false), vec!["example"]);
+    assert_eq!(input_names(&registry, 2, true), vec!["input"]);
+
+    assert_eq!(
+        check(find(&registry, 5, 1, "example"), &Answer::from("CMZ")),
+        Status::Pass
+    );
+    assert!(matches!(
+        check(find(&registry, 1, 2, "example"), &Answer::from(41000)),
+        Status::Fail(_)
+    ));
+    assert!(matches!(
+        check(find(&registry, 1, 1, "example"), &Answer::from(24000)),
+        Status::Missing(_)
+    ));
+}
+
+#[test]
+fn test_fixtures() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+    let registry = load_registry(&registry_path(dir)).unwrap();
+
+    for day in &crate::DAYS {
+        for part in 1..=2 {
+            assert!(
+                find(&registry, day.number, part, "example").is_some(),
+                "Day {} part {} has no example fixture",
+                day.number,
+                part
+            );
+        }
+    }
+
+    let failures = check_dir(dir, &crate::DAYS, &registry, false)
+        .into_iter()
+        .filter(|e| e.status != Status::Pass)
+        .collect::<Vec<Check>>();
+
+    assert!(failures.is_empty(), "{:#?}", failures);
+}
+
+#[test]
+fn test_default_registry() {
+    // Only the registry itself is checked in; personal inputs are not.
+    let path = registry_path(env!("CARGO_MANIFEST_DIR"));
+
+    assert!(load_registry(&path)
+        .unwrap()
+        .iter()
+        .all(|e| e.input == "input"));
+}